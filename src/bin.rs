@@ -1,31 +1,69 @@
-use anyhow::{anyhow, Context, Result};
-use clap::{builder::Str, command, Arg, ArgAction, ArgMatches, Command};
-use log::{debug, error, LevelFilter};
+use std::{
+    io::{self, Read},
+    path::Path,
+    time::Duration,
+};
 
-use puzzlelib::{get_puzzle, puzzle_names, puzzles};
+use anyhow::{Context, Result};
+use clap::{builder::Str, command, value_parser, Arg, ArgAction, ArgMatches, Command};
+use log::{debug, warn, LevelFilter};
+use rayon::prelude::*;
+use serde::Serialize;
+
+mod exit;
+mod report;
+
+use exit::{enable_backtraces, report_error, CliError, ExitStatus, EXIT_CODES_HELP};
+use puzzlelib::{
+    get_puzzle, puzzle_names, puzzles, InputSource, KnownAnswers, Part, Puzzle, PuzzleError, Stats,
+    Verification, ANSWERS_FILE_NAME, CONFIG_FILE_NAME, INPUT_DIR_VAR,
+};
+use report::{print_records, Format, Record, FORMAT_NAMES};
 
 fn main() {
     let matches = command!()
+        .after_help(EXIT_CODES_HELP)
         .arg(
             Arg::new("debug")
                 .short('d')
                 .long("debug")
+                .help("Logs debug messages and prints error backtraces")
                 .required(false)
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("input-dir")
+                .long("input-dir")
+                .value_name("DIR")
+                .help(format!(
+                    "Searches DIR for day inputs before ${INPUT_DIR_VAR}, {CONFIG_FILE_NAME}, the executable directory and any embedded inputs"
+                ))
+                .global(true),
+        )
+        .arg(
+            Arg::new("time")
+                .short('t')
+                .long("time")
+                .help("Prints how long loading, parsing and each part took")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
         .add_puzzle_commands()
         .get_matches();
 
     let debug: bool = *matches.get_one("debug").unwrap_or(&false);
     init_logging(debug);
+    if debug {
+        enable_backtraces();
+    }
 
-    match matches.subcommand() {
-        Some(command) => {
-            if let Err(error) = run_command(command) {
-                error!("{}", error);
-            }
-        }
-        None => error!("Missing required command"),
+    let result = match matches.subcommand() {
+        Some(command) => run_command(command),
+        None => Err(CliError::usage("Missing required command".to_owned()).into()),
+    };
+    if let Err(err) = result {
+        report_error(&err, debug);
+        ExitStatus::of(&err).exit();
     }
 }
 
@@ -42,56 +80,343 @@ fn init_logging(debug: bool) {
 
 fn run_command(command: (&str, &ArgMatches)) -> Result<()> {
     match command {
-        ("all", _) => run_all_puzzles(),
+        ("all", args) => run_all_puzzles(
+            &input_source(args)?,
+            args.get_flag("parallel"),
+            output_format(args),
+        ),
         ("day", args) => run_day_command(args),
-        _ => Err(anyhow!(
-            "Use the 'all' or 'day' command to run one or more puzzles",
-        )),
+        ("verify", args) => run_verify_command(args),
+        ("bench", args) => run_bench_command(args),
+        _ => Err(CliError::usage(
+            "Use the 'all' or 'day' command to run one or more puzzles".to_owned(),
+        )
+        .into()),
+    }
+}
+
+fn run_all_puzzles(source: &InputSource, parallel: bool, format: Format) -> Result<()> {
+    let parts = [Part::One, Part::Two];
+    let run = |puzzle: Puzzle| {
+        debug!("Running day {}:", puzzle.name());
+        let result = puzzle.run(source, &parts);
+        (puzzle, result)
+    };
+    // rayon's collect keeps the day order
+    let runs: Vec<_> = if parallel {
+        puzzles().into_par_iter().map(run).collect()
+    } else {
+        puzzles().into_iter().map(run).collect()
+    };
+
+    let records = runs
+        .into_iter()
+        .flat_map(|(puzzle, result)| Record::from_run(&puzzle, result))
+        .collect::<Vec<_>>();
+    print_records(&records, format)?;
+
+    let failures = records.iter().filter(|record| record.is_failure()).count();
+    if let Some(status) = records
+        .iter()
+        .find(|record| record.is_failure())
+        .and_then(|record| record.exit_status)
+    {
+        return Err(CliError::new(status, format!("{failures} puzzle runs failed")).into());
     }
+    Ok(())
 }
 
-fn run_all_puzzles() -> Result<()> {
-    for puzzle in puzzles() {
-        debug!("Running day {} part one:", puzzle.name());
-        puzzle.run_part_one()?;
-        debug!("Running day {} part two:", puzzle.name());
-        puzzle.run_part_two()?;
+fn run_verify_command(args: &ArgMatches) -> Result<()> {
+    let answers_path = args
+        .get_one::<String>("answers")
+        .expect("answers has a default");
+    let answers = KnownAnswers::load(Path::new(answers_path))?;
+    let source = input_source(args)?;
+
+    let (mut verified, mut failures, mut skipped) = (0, 0, 0);
+    println!(
+        "{:<13} {:<4} {:<8} {:<20} Expected",
+        "Day", "Part", "Result", "Answer"
+    );
+    for puzzle in puzzles().iter().filter(|puzzle| puzzle.is_implemented()) {
+        debug!("Verifying day {}:", puzzle.name());
+        let parsed = puzzle.load(&source);
+        for &part in puzzle.parts() {
+            let expected = answers.expected(puzzle.name(), part);
+            let (result, answer) = match parsed.as_ref().map(|parsed| parsed.part(part)) {
+                Ok(Ok(answer)) => match answers.verify(puzzle.name(), part, &answer) {
+                    Verification::Pass => ("pass", answer.to_string()),
+                    Verification::Fail { .. } => ("FAIL", answer.to_string()),
+                    Verification::Missing => ("missing", answer.to_string()),
+                },
+                // the searched locations and located error snippets don't fit a table row
+                Err(PuzzleError::LoadInputFailure { path, .. }) if expected.is_some() => {
+                    ("NO INPUT", path.to_owned())
+                }
+                Err(PuzzleError::LoadInputFailure { path, .. }) => ("no input", path.to_owned()),
+                Ok(Err(err)) => ("ERROR", first_line(&err)),
+                Err(err) => ("ERROR", first_line(err)),
+            };
+            match result {
+                "pass" => verified += 1,
+                "FAIL" | "NO INPUT" | "ERROR" => failures += 1,
+                _ => skipped += 1,
+            }
+            println!(
+                "{:<13} {:<4} {:<8} {:<20} {}",
+                puzzle.name(),
+                part,
+                result,
+                answer,
+                expected.as_deref().unwrap_or("-")
+            );
+        }
     }
 
+    println!("{verified} verified, {failures} failed, {skipped} skipped");
+
+    if failures > 0 {
+        return Err(CliError::new(
+            ExitStatus::Failure,
+            format!("{failures} puzzle answers failed verification"),
+        )
+        .into());
+    }
+    if verified == 0 {
+        return Err(CliError::new(
+            ExitStatus::Failure,
+            "no puzzle answers were verified".to_owned(),
+        )
+        .into());
+    }
     Ok(())
 }
 
+fn first_line(err: &PuzzleError) -> String {
+    err.to_string()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
 fn run_day_command(args: &ArgMatches) -> Result<()> {
     match args.subcommand() {
         Some((day_name, args)) => match args.subcommand() {
             Some(("part", args)) => match args.subcommand() {
-                Some((part_name, _)) => run_day_puzzle(day_name, part_name),
-                _ => Err(anyhow!("Missing the part name")),
+                Some((part_name, args)) => run_day_puzzle(
+                    day_name,
+                    part_name,
+                    &input_source(args)?,
+                    args.get_flag("time"),
+                    output_format(args),
+                ),
+                _ => Err(CliError::usage("Missing the part name".to_owned()).into()),
             },
             Some((unexpected_command, _)) => {
-                Err(anyhow!("Unhandled command '{}'", unexpected_command))
+                Err(CliError::usage(format!("Unhandled command '{unexpected_command}'")).into())
             }
-            _ => Err(anyhow!("Missing the part")),
+            _ => Err(CliError::usage("Missing the part".to_owned()).into()),
+        },
+        _ => Err(CliError::usage("Missing the day name".to_owned()).into()),
+    }
+}
+
+fn input_source(args: &ArgMatches) -> Result<InputSource> {
+    let input = args.try_get_one::<String>("input").ok().flatten();
+    match input.map(String::as_str) {
+        None => match args.get_one::<String>("input-dir") {
+            Some(dir) => Ok(InputSource::Directory(dir.into())),
+            None => Ok(InputSource::Default),
         },
-        _ => Err(anyhow!("Missing the day name")),
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .context("Failed to read input from stdin")?;
+            Ok(InputSource::Text(input))
+        }
+        Some(path) => Ok(InputSource::Path(path.into())),
+    }
+}
+
+fn output_format(args: &ArgMatches) -> Format {
+    Format::from_name(
+        args.get_one::<String>("format")
+            .expect("format has a default"),
+    )
+}
+
+fn run_day_puzzle(
+    name: &str,
+    part: &str,
+    source: &InputSource,
+    time: bool,
+    format: Format,
+) -> Result<()> {
+    let puzzle = get_puzzle(name)
+        .ok_or_else(|| CliError::usage(format!("Unrecognized puzzle name: '{name}'")))?;
+    let parts = match part {
+        "one" => &[Part::One][..],
+        "two" => &[Part::Two][..],
+        _ => puzzle.parts(),
+    };
+    if let Some(part) = parts.iter().find(|&&part| !puzzle.has_part(part)) {
+        return Err(CliError::usage(format!("Day {name} has no part {part}")).into());
+    }
+    if format == Format::Text {
+        return run_puzzle(&puzzle, source, parts, time);
+    }
+
+    let records = Record::from_run(&puzzle, puzzle.run(source, parts));
+    print_records(&records, format)?;
+    if let Some(record) = records.iter().find(|record| record.error.is_some()) {
+        return Err(CliError::new(
+            record.exit_status.unwrap_or(ExitStatus::Failure),
+            record.error.clone().unwrap_or_default(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn run_puzzle(puzzle: &Puzzle, source: &InputSource, parts: &[Part], time: bool) -> Result<()> {
+    debug!("Running day {}:", puzzle.name());
+    let run = puzzle.run(source, parts)?;
+    if time {
+        println!(
+            "Day {} input: loaded in {:?}, parsed in {:?}",
+            puzzle.name(),
+            run.load,
+            run.parse
+        );
     }
+    for part_run in run.parts {
+        let answer = part_run.answer?;
+        if time {
+            println!(
+                "Day {} part {}: {answer} ({:?})",
+                puzzle.name(),
+                part_run.part,
+                part_run.duration
+            );
+        } else {
+            println!("Day {} part {}: {answer}", puzzle.name(), part_run.part);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct BenchResult {
+    day: String,
+    phase: String,
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
 }
 
-fn run_day_puzzle(name: &str, part: &str) -> Result<()> {
-    let puzzle =
-        get_puzzle(name).with_context(|| format!("Unrecognized puzzle name: '{}'", name))?;
-    if part == "one" || part == "both" {
-        debug!("Running day {} part one:", puzzle.name());
-        puzzle.run_part_one()?;
+impl BenchResult {
+    fn new(day: &str, phase: &str, durations: &[Duration]) -> Option<BenchResult> {
+        let stats = Stats::new(durations)?;
+        Some(BenchResult {
+            day: day.to_owned(),
+            phase: phase.to_owned(),
+            runs: durations.len(),
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            max_ns: stats.max.as_nanos() as u64,
+        })
     }
-    if part == "two" || part == "both" {
-        debug!("Running day {} part two:", puzzle.name());
-        puzzle.run_part_two()?;
+}
+
+fn run_bench_command(args: &ArgMatches) -> Result<()> {
+    let runs = *args.get_one::<u64>("runs").expect("runs has a default") as usize;
+    let format = args
+        .get_one::<String>("format")
+        .expect("format has a default");
+    let source = input_source(args)?;
+    let selected = args
+        .get_many::<String>("day")
+        .map(|names| names.cloned().collect::<Vec<_>>());
+
+    let mut results = vec![];
+    for puzzle in puzzles().iter().filter(|puzzle| puzzle.is_implemented()) {
+        if let Some(selected) = &selected {
+            if !selected.iter().any(|name| name == puzzle.name()) {
+                continue;
+            }
+        }
+        match bench_puzzle(puzzle, &source, runs) {
+            Ok(puzzle_results) => results.extend(puzzle_results),
+            Err(err) => warn!("Skipping day {}: {err}", puzzle.name()),
+        }
     }
 
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else {
+        println!(
+            "{:<13} {:<8} {:>4} {:>14} {:>14} {:>14}",
+            "Day", "Phase", "Runs", "Min", "Median", "Max"
+        );
+        for result in &results {
+            println!(
+                "{:<13} {:<8} {:>4} {:>14} {:>14} {:>14}",
+                result.day,
+                result.phase,
+                result.runs,
+                format!("{:?}", Duration::from_nanos(result.min_ns)),
+                format!("{:?}", Duration::from_nanos(result.median_ns)),
+                format!("{:?}", Duration::from_nanos(result.max_ns)),
+            );
+        }
+    }
     Ok(())
 }
 
+fn bench_puzzle(puzzle: &Puzzle, source: &InputSource, runs: usize) -> Result<Vec<BenchResult>> {
+    let parts = puzzle.parts();
+    let mut load = vec![];
+    let mut parse = vec![];
+    let mut part_durations = vec![vec![]; parts.len()];
+    for run_index in 0..runs {
+        debug!("Benchmarking day {} run {}:", puzzle.name(), run_index + 1);
+        let run = puzzle.run(source, parts)?;
+        load.push(run.load);
+        parse.push(run.parse);
+        for (durations, part_run) in part_durations.iter_mut().zip(run.parts) {
+            part_run.answer?;
+            durations.push(part_run.duration);
+        }
+    }
+
+    let name = puzzle.name();
+    let phases = parts
+        .iter()
+        .zip(&part_durations)
+        .map(|(part, durations)| BenchResult::new(name, &format!("part {part}"), durations));
+    Ok([
+        BenchResult::new(name, "load", &load),
+        BenchResult::new(name, "parse", &parse),
+    ]
+    .into_iter()
+    .chain(phases)
+    .flatten()
+    .collect())
+}
+
+fn format_arg() -> Arg {
+    Arg::new("format")
+        .short('f')
+        .long("format")
+        .value_parser(FORMAT_NAMES)
+        .default_value("text")
+        .help("Prints the outcomes as text, JSON records or CSV")
+}
+
 trait AddPuzzlesCommands {
     fn add_puzzle_commands(self) -> Self;
 }
@@ -101,7 +426,16 @@ impl AddPuzzlesCommands for Command {
         let mut day_command = command!("day")
             .disable_help_subcommand(true)
             .subcommand_required(true)
-            .about("Runs a specified day's puzzle");
+            .about("Runs a specified day's puzzle")
+            .arg(
+                Arg::new("input")
+                    .short('i')
+                    .long("input")
+                    .value_name("PATH")
+                    .help("Reads the puzzle input from PATH, or from stdin when PATH is '-'")
+                    .global(true),
+            )
+            .arg(format_arg().global(true));
         for (index, name) in puzzle_names().iter().enumerate() {
             let alias = Str::from((index + 1).to_string());
             day_command = day_command.subcommand(
@@ -132,9 +466,60 @@ impl AddPuzzlesCommands for Command {
         self = self.subcommand_required(true).subcommand(
             command!("all")
                 .disable_help_subcommand(true)
-                .about("Runs all puzzles"),
+                .about("Runs all puzzles and prints a summary of their outcomes")
+                .arg(
+                    Arg::new("parallel")
+                        .short('p')
+                        .long("parallel")
+                        .help("Runs the puzzles in parallel")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(format_arg()),
         );
         self = self.subcommand(day_command);
+        self = self.subcommand(
+            command!("verify")
+                .disable_help_subcommand(true)
+                .about("Runs all implemented puzzles and compares them to the known answers")
+                .arg(
+                    Arg::new("answers")
+                        .short('a')
+                        .long("answers")
+                        .value_name("PATH")
+                        .default_value(ANSWERS_FILE_NAME)
+                        .help("The TOML file of known answers keyed by day then part"),
+                ),
+        );
+        self = self.subcommand(
+            command!("bench")
+                .disable_help_subcommand(true)
+                .about("Runs implemented puzzles repeatedly and reports min/median/max durations")
+                .arg(
+                    Arg::new("runs")
+                        .short('n')
+                        .long("runs")
+                        .value_name("N")
+                        .value_parser(value_parser!(u64).range(1..))
+                        .default_value("10")
+                        .help("The number of times each puzzle is run"),
+                )
+                .arg(
+                    Arg::new("format")
+                        .short('f')
+                        .long("format")
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                        .help("Prints a text table or JSON"),
+                )
+                .arg(
+                    Arg::new("day")
+                        .long("day")
+                        .value_name("NAME")
+                        .value_parser(puzzle_names())
+                        .action(ArgAction::Append)
+                        .help("Benchmarks only the named day, may be repeated"),
+                ),
+        );
         self
     }
 }
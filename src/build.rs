@@ -1,38 +1,68 @@
 use std::{
     env,
+    fmt::Write,
     path::{Path, PathBuf},
 };
 
+const INPUTS_DIR: &str = "src/puzzles";
+const EMBEDDED_INPUTS_FILE: &str = "embedded_inputs.rs";
+
 fn main() {
-    copy_data_files_to_target_dir()
-}
+    let inputs_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(INPUTS_DIR);
+    println!("cargo:rerun-if-changed={}", inputs_dir.display());
 
-fn copy_data_files_to_target_dir() {
-    let target_dir = get_output_path();
-    let dir = std::env::current_dir().expect("get current dir");
-    println!("current dir: {dir:?}");
-    let dir = std::fs::read_dir("./src/puzzles").expect("failed to read puzzles directory");
-    for data_entry in dir
-        .map(|file_path| file_path.expect("failed to read puzzle file"))
-        .filter(|file_path| file_path.file_name().to_string_lossy().ends_with(".input"))
-    {
-        let source = data_entry.path();
-        let target =
-            Path::new(&target_dir).join(source.file_name().expect("failed to read file name"));
-        std::fs::copy(&source, &target).unwrap_or_else(|_| {
-            panic!(
-                "failed to copy '{}' to '{}'",
-                source.display(),
-                target.display()
-            );
-        });
+    let input_files = input_files(&inputs_dir);
+    for input_file in &input_files {
+        println!("cargo:rerun-if-changed={}", input_file.display());
     }
+
+    let embed = env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some();
+    write_embedded_inputs(if embed { &input_files } else { &[] });
 }
 
-fn get_output_path() -> PathBuf {
-    let manifest_dir_string = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let build_type = env::var("PROFILE").unwrap();
-    Path::new(&manifest_dir_string)
-        .join("target")
-        .join(build_type)
+fn input_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        println!(
+            "cargo:warning=no puzzle inputs directory '{}'",
+            dir.display()
+        );
+        return vec![];
+    };
+    let mut input_files = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "input"))
+        .collect::<Vec<_>>();
+    input_files.sort();
+    input_files
+}
+
+fn write_embedded_inputs(input_files: &[PathBuf]) {
+    let mut source = String::new();
+    if input_files.is_empty() {
+        source.push_str(
+            "pub(crate) fn embedded_input(_file_name: &str) -> Option<&'static str> {\n    None\n}\n",
+        );
+    } else {
+        source.push_str(
+            "pub(crate) fn embedded_input(file_name: &str) -> Option<&'static str> {\n    match file_name {\n",
+        );
+        for input_file in input_files {
+            let file_name = input_file
+                .file_name()
+                .expect("input file has a name")
+                .to_string_lossy();
+            writeln!(
+                source,
+                "        {file_name:?} => Some(include_str!({:?})),",
+                input_file.display().to_string()
+            )
+            .expect("write to string");
+        }
+        source.push_str("        _ => None,\n    }\n}\n");
+    }
+
+    let target = Path::new(&env::var("OUT_DIR").unwrap()).join(EMBEDDED_INPUTS_FILE);
+    std::fs::write(&target, source)
+        .unwrap_or_else(|err| panic!("failed to write '{}': {err}", target.display()));
 }
@@ -0,0 +1,166 @@
+use std::{env, process};
+
+use puzzlelib::PuzzleError;
+use thiserror::Error;
+
+pub const EXIT_CODES_HELP: &str = "Exit codes:
+  1  unexpected failure or failed verification
+  2  invalid command line usage
+  3  puzzle input could not be loaded
+  4  puzzle input is invalid
+  5  puzzle is not implemented";
+
+/// The process exit code of each failure class.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExitStatus {
+    Failure = 1,
+    Usage = 2,
+    LoadInput = 3,
+    InvalidInput = 4,
+    NotImplemented = 5,
+}
+
+impl ExitStatus {
+    pub fn from_error(err: &PuzzleError) -> ExitStatus {
+        match err {
+            PuzzleError::LoadInputFailure { .. } => ExitStatus::LoadInput,
+            PuzzleError::InvalidInputAt { .. } | PuzzleError::InvalidInput { .. } => {
+                ExitStatus::InvalidInput
+            }
+            PuzzleError::NotImplemented => ExitStatus::NotImplemented,
+            PuzzleError::Unexpected { .. } | PuzzleError::UnexpectedErr { .. } => {
+                ExitStatus::Failure
+            }
+        }
+    }
+
+    pub fn of(err: &anyhow::Error) -> ExitStatus {
+        for cause in err.chain() {
+            if let Some(err) = cause.downcast_ref::<CliError>() {
+                return err.status;
+            }
+            if let Some(err) = cause.downcast_ref::<PuzzleError>() {
+                return ExitStatus::from_error(err);
+            }
+        }
+        ExitStatus::Failure
+    }
+
+    pub fn exit(self) -> ! {
+        process::exit(self as i32)
+    }
+}
+
+/// A failure that isn't a `PuzzleError` but still has its own exit status,
+/// such as a usage mistake or a summary of failed puzzle runs.
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct CliError {
+    status: ExitStatus,
+    message: String,
+}
+
+impl CliError {
+    pub fn new(status: ExitStatus, message: String) -> Self {
+        CliError { status, message }
+    }
+
+    pub fn usage(message: String) -> Self {
+        CliError::new(ExitStatus::Usage, message)
+    }
+}
+
+/// Makes anyhow capture backtraces for errors created from here on.
+pub fn enable_backtraces() {
+    if env::var_os("RUST_LIB_BACKTRACE").is_none() {
+        env::set_var("RUST_LIB_BACKTRACE", "1");
+    }
+}
+
+/// Prints the error and its sources to stderr, skipping sources whose message
+/// is already part of the error's own message. Debug output includes the
+/// backtrace.
+pub fn report_error(err: &anyhow::Error, debug: bool) {
+    if debug {
+        eprintln!("error: {err:?}");
+        return;
+    }
+    eprintln!("error: {err}");
+    let mut shown = err.to_string();
+    for cause in err.chain().skip(1) {
+        let message = cause.to_string();
+        if !shown.contains(&message) {
+            eprintln!("  caused by: {message}");
+        }
+        shown = message;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use anyhow::Context;
+    use puzzlelib::{PuzzleError, Span};
+
+    use super::{CliError, ExitStatus};
+
+    #[test]
+    fn from_error() {
+        let load = PuzzleError::LoadInputFailure {
+            path: "day_01.input".to_owned(),
+            source: io::Error::new(io::ErrorKind::NotFound, "input file not found"),
+            searched: vec![],
+        };
+        let invalid = PuzzleError::InvalidInput {
+            reason: "bad".to_owned(),
+        };
+        let invalid_at = PuzzleError::InvalidInputAt {
+            span: Span {
+                line: 1,
+                column: 1,
+                len: 1,
+            },
+            text: "x".to_owned(),
+            reason: "bad".to_owned(),
+            snippet: String::new(),
+        };
+        let unexpected = PuzzleError::Unexpected {
+            message: "bad".to_owned(),
+        };
+        assert_eq!(ExitStatus::LoadInput, ExitStatus::from_error(&load));
+        assert_eq!(ExitStatus::InvalidInput, ExitStatus::from_error(&invalid));
+        assert_eq!(
+            ExitStatus::InvalidInput,
+            ExitStatus::from_error(&invalid_at)
+        );
+        assert_eq!(
+            ExitStatus::NotImplemented,
+            ExitStatus::from_error(&PuzzleError::NotImplemented)
+        );
+        assert_eq!(ExitStatus::Failure, ExitStatus::from_error(&unexpected));
+        assert_eq!(
+            [3, 4, 5],
+            [
+                ExitStatus::LoadInput,
+                ExitStatus::InvalidInput,
+                ExitStatus::NotImplemented
+            ]
+            .map(|status| status as i32)
+        );
+    }
+
+    #[test]
+    fn of_error_chain() {
+        let err = Err::<(), _>(PuzzleError::NotImplemented)
+            .context("running day one")
+            .expect_err("error");
+        assert_eq!(ExitStatus::NotImplemented, ExitStatus::of(&err));
+        let err = anyhow::Error::from(CliError::usage("unknown day".to_owned()));
+        assert_eq!(ExitStatus::Usage, ExitStatus::of(&err));
+        assert_eq!(
+            ExitStatus::Failure,
+            ExitStatus::of(&anyhow::anyhow!("boom"))
+        );
+    }
+}
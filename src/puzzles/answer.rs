@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn answer_from() {
+        assert_eq!(Answer::Unsigned(142), Answer::from(142_u32));
+        assert_eq!(Answer::Unsigned(142), Answer::from(142_usize));
+        assert_eq!(Answer::Signed(-3), Answer::from(-3_i64));
+        assert_eq!(Answer::Text("AB".to_owned()), Answer::from("AB"));
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!("142", Answer::Unsigned(142).to_string());
+        assert_eq!("-3", Answer::Signed(-3).to_string());
+        assert_eq!("AB", Answer::Text("AB".to_owned()).to_string());
+    }
+}
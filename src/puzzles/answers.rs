@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{Answer, Part, PuzzleError, Result};
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+enum KnownAnswer {
+    Integer(i64),
+    Text(String),
+}
+
+impl Display for KnownAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KnownAnswer::Integer(value) => write!(f, "{value}"),
+            KnownAnswer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    one: Option<KnownAnswer>,
+    two: Option<KnownAnswer>,
+}
+
+/// Recorded puzzle answers keyed by day name then part name, e.g.
+///
+/// ```toml
+/// [one]
+/// one = 142
+/// two = 281
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct KnownAnswers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verification {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl KnownAnswers {
+    pub fn load(path: &Path) -> Result<KnownAnswers> {
+        fs::read_to_string(path)
+            .map_err(|err| PuzzleError::from_io_error(path, err))?
+            .parse()
+    }
+
+    pub fn expected(&self, day: &str, part: Part) -> Option<String> {
+        let answers = self.days.get(day)?;
+        let answer = match part {
+            Part::One => answers.one.as_ref(),
+            Part::Two => answers.two.as_ref(),
+        };
+        answer.map(|answer| answer.to_string())
+    }
+
+    pub fn verify(&self, day: &str, part: Part, answer: &Answer) -> Verification {
+        match self.expected(day, part) {
+            Some(expected) if expected == answer.to_string() => Verification::Pass,
+            Some(expected) => Verification::Fail { expected },
+            None => Verification::Missing,
+        }
+    }
+}
+
+impl FromStr for KnownAnswers {
+    type Err = PuzzleError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let days = toml::from_str(input).map_err(|err| {
+            PuzzleError::invalid_input(&format!("invalid answers: {}", err.message()))
+        })?;
+        Ok(KnownAnswers { days })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, KnownAnswers, Part, PuzzleError, Verification};
+
+    const INPUT: &str = r#"
+[one]
+one = 142
+two = 281
+
+[nine]
+two = -3
+
+[thirteen]
+one = "ABC"
+"#;
+
+    #[test]
+    fn verify() {
+        let answers: KnownAnswers = INPUT.parse().expect("valid answers");
+        assert_eq!(
+            Verification::Pass,
+            answers.verify("one", Part::One, &Answer::Unsigned(142))
+        );
+        assert_eq!(
+            Verification::Fail {
+                expected: "281".to_owned()
+            },
+            answers.verify("one", Part::Two, &Answer::Unsigned(280))
+        );
+        assert_eq!(
+            Verification::Pass,
+            answers.verify("nine", Part::Two, &Answer::Signed(-3))
+        );
+        assert_eq!(
+            Verification::Missing,
+            answers.verify("nine", Part::One, &Answer::Signed(114))
+        );
+        assert_eq!(
+            Verification::Pass,
+            answers.verify("thirteen", Part::One, &Answer::from("ABC"))
+        );
+        assert_eq!(
+            Verification::Missing,
+            answers.verify("two", Part::One, &Answer::Unsigned(8))
+        );
+    }
+
+    #[test]
+    fn invalid_part() {
+        assert!(matches!(
+            "[one]\nthree = 1".parse::<KnownAnswers>(),
+            Err(PuzzleError::InvalidInput { .. })
+        ));
+    }
+}
@@ -1,17 +1,23 @@
-use crate::{read_input_file, Result};
+use crate::{Answer, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(1, None)?;
-    let sum = part_one::solve(input.trim())?;
-    println!("Day one part one: sum of calibration values: {sum}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_owned())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(1, None)?;
-    let sum = part_two::solve(input.trim())?;
-    println!("Day one part two: sum of calibration values: {sum}");
-    Ok(())
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
 }
 
 mod part_one {
@@ -24,8 +30,8 @@ mod part_one {
             }
             None
         }
-        let calibration_values = input.lines().enumerate().map(|(line, input)| {
-            let values = input
+        let calibration_values = input.lines().map(|line| {
+            let values = line
                 .chars()
                 .fold(None, |values, c| match (values, u32_from_char(c)) {
                     (None, Some(value)) => Some((value, value)),
@@ -33,14 +39,15 @@ mod part_one {
                     (Some(values), None) => Some(values),
                     _ => None,
                 });
-            (line + 1, values)
+            (line, values)
         });
         let mut sum = 0;
         for (line, values) in calibration_values {
             match values {
                 Some((first, last)) => sum += (first * 10) + last,
                 None => {
-                    return Err(PuzzleError::invalid_line_input(
+                    return Err(PuzzleError::invalid_input_at(
+                        input,
                         line,
                         "line does not have any digits",
                     ))
@@ -98,11 +105,11 @@ mod part_two {
                 _ => None,
             }
         }
-        let calibration_values = input.lines().enumerate().map(|(line, input)| {
+        let calibration_values = input.lines().map(|line| {
             let first = RE_DIGITS
-                .captures(input)
+                .captures(line)
                 .and_then(|capture| capture.get(1).map(|sub_capture| sub_capture.as_str()));
-            let input_reverse: String = input.chars().rev().collect();
+            let input_reverse: String = line.chars().rev().collect();
             let last = RE_REVERSE_DIGITS
                 .captures(&input_reverse)
                 .and_then(|capture| {
@@ -117,7 +124,7 @@ mod part_two {
                 },
                 _ => None,
             };
-            (line + 1, values)
+            (line, values)
         });
 
         let mut sum = 0;
@@ -125,7 +132,8 @@ mod part_two {
             match values {
                 Some((first, last)) => sum += (first * 10) + last,
                 None => {
-                    return Err(PuzzleError::invalid_line_input(
+                    return Err(PuzzleError::invalid_input_at(
+                        input,
                         line,
                         "line does not have any digits",
                     ));
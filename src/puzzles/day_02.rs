@@ -1,26 +1,33 @@
-use crate::{read_input_file, Result};
-
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(2, None)?;
-    let bag_content = Set {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
-    let sum = part_one::solve(input.trim(), &bag_content)?;
-    println!("Day two part one: sum of possible game identifiers: {sum}");
-    Ok(())
-}
+use crate::{Answer, Result, Solution};
+
+const BAG_CONTENT: Set = Set {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<(usize, Vec<Set>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input, &BAG_CONTENT)?;
+        Ok(sum.into())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(2, None)?;
-    let sum = part_two::solve(input.trim())?;
-    println!("Day two part two: sum of required bag set powers: {sum}");
-    Ok(())
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
 }
 
 #[derive(Debug, PartialEq, PartialOrd)]
-struct Set {
+pub struct Set {
     red: usize,
     green: usize,
     blue: usize,
@@ -53,19 +60,11 @@ impl Set {
 }
 
 mod part_one {
-    use super::{parser, Result, Set};
-    use lazy_static::lazy_static;
-    use regex::Regex;
+    use super::{Result, Set};
 
-    lazy_static! {
-        static ref RE_GAME_ID: Regex = Regex::new(r"Game (\d+)").expect("failed game id regex");
-        static ref RE_SETS: Regex =
-            Regex::new(r"(\d+) (red|green|blue),?").expect("failed sets regex");
-    }
-
-    pub fn solve(input: &str, bag_content: &Set) -> Result<usize> {
-        let game_sets = parser::parse(input)?
-            .into_iter()
+    pub fn solve(games: &[(usize, Vec<Set>)], bag_content: &Set) -> Result<usize> {
+        let game_sets = games
+            .iter()
             .filter(|(_, sets)| sets.iter().all(|set| bag_content.contains(set)));
         let sum = game_sets.fold(0, |sum, (game_id, _sets)| sum + game_id);
 
@@ -74,7 +73,7 @@ mod part_one {
 
     #[cfg(test)]
     mod tests {
-        use super::{solve, Set};
+        use super::{super::parser::parse, solve, Set};
 
         #[test]
         fn part_one() {
@@ -89,28 +88,27 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
                 green: 13,
                 blue: 14,
             };
-            assert_eq!(Ok(8), solve(input.trim(), &bag_content));
+            let games = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(8), solve(&games, &bag_content));
         }
     }
 }
 
 mod part_two {
-    use super::{parser, Result, Set};
+    use super::{Result, Set};
 
-    pub fn solve(input: &str) -> Result<u32> {
-        Ok(parser::parse(input)?
-            .into_iter()
-            .fold(0usize, |sum, (_, sets)| {
-                let super_set = sets
-                    .into_iter()
-                    .fold(Set::empty(), |super_set, set| super_set.union(&set));
-                sum + super_set.power()
-            }) as u32)
+    pub fn solve(games: &[(usize, Vec<Set>)]) -> Result<u32> {
+        Ok(games.iter().fold(0usize, |sum, (_, sets)| {
+            let super_set = sets
+                .iter()
+                .fold(Set::empty(), |super_set, set| super_set.union(set));
+            sum + super_set.power()
+        }) as u32)
     }
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         #[test]
         fn test_two() {
@@ -120,7 +118,8 @@ Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
-            assert_eq!(Ok(2286), solve(input.trim()));
+            let games = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(2286), solve(&games));
         }
     }
 }
@@ -178,28 +177,33 @@ mod parser {
     pub fn parse(input: &str) -> Result<Vec<(usize, Vec<Set>)>> {
         input
             .lines()
-            .enumerate()
-            .map(|(line, input)| {
-                let split_input: Vec<&str> = input.split(':').collect();
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let split_input: Vec<&str> = line.split(':').collect();
                 if split_input.len() != 2 {
-                    return Err(PuzzleError::invalid_line_input(
+                    return Err(PuzzleError::invalid_input_at(
+                        input,
                         line,
-                        "incorrect number of semi colons",
+                        "incorrect number of colons",
                     ));
                 }
                 let id = parse_game_id(split_input[0]).ok_or_else(|| {
-                    PuzzleError::invalid_line_input(line, "missing game identifier")
+                    PuzzleError::invalid_input_at(input, split_input[0], "missing game identifier")
                 })?;
                 let sets = split_input[1]
                     .split(';')
                     .map(|set| {
-                        parse_game_set(set).ok_or_else(|| {
-                            PuzzleError::invalid_line_input(line, "invalid set '{set}'")
-                        })
+                        parse_game_set(set)
+                            .ok_or_else(|| PuzzleError::invalid_input_at(input, set, "invalid set"))
                     })
                     .collect::<Result<Vec<Set>>>()?;
                 if sets.is_empty() {
-                    return Err(PuzzleError::invalid_line_input(line, "missing games sets"));
+                    return Err(PuzzleError::invalid_input_at(
+                        input,
+                        line,
+                        "missing games sets",
+                    ));
                 }
                 Ok((id, sets))
             })
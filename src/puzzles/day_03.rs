@@ -1,21 +1,27 @@
-use crate::{read_input_file, Result};
+use crate::{Answer, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(3, None)?;
-    let sum = part_one::solve(input.trim())?;
-    println!("Day three part one: sum of part numbers: {sum}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = EngineSchematic;
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(3, None)?;
-    let sum = part_two::solve(input.trim())?;
-    println!("Day three part two: sum of gear ratios: {sum}");
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
 }
 
 #[derive(Debug)]
-struct EngineSchematic {
+pub struct EngineSchematic {
     part_numbers: Vec<u32>,
     gear_ratios: Vec<u32>,
 }
@@ -31,17 +37,16 @@ impl EngineSchematic {
 }
 
 mod part_one {
-    use super::{parser, Result};
+    use super::{EngineSchematic, Result};
 
-    pub fn solve(input: &str) -> Result<u32> {
-        let schematic = parser::parse(input)?;
+    pub fn solve(schematic: &EngineSchematic) -> Result<u32> {
         let sum = schematic.part_numbers().iter().sum();
         Ok(sum)
     }
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         #[test]
         fn part_one() {
@@ -56,23 +61,23 @@ mod part_one {
 ......755.
 ...$.*....
 .664.598.."#;
-            assert_eq!(Ok(4361), solve(input.trim()));
+            let schematic = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(4361), solve(&schematic));
         }
     }
 }
 
 mod part_two {
-    use super::{parser, Result};
+    use super::{EngineSchematic, Result};
 
-    pub fn solve(input: &str) -> Result<u32> {
-        let schematic = parser::parse(input)?;
+    pub fn solve(schematic: &EngineSchematic) -> Result<u32> {
         let sum = schematic.gear_ratios().iter().sum();
         Ok(sum)
     }
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         #[test]
         fn part_one() {
@@ -87,7 +92,8 @@ mod part_two {
 ......755.
 ...$.*....
 .664.598.."#;
-            assert_eq!(Ok(467835), solve(input.trim()));
+            let schematic = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(467835), solve(&schematic));
         }
     }
 }
@@ -97,6 +103,7 @@ mod parser {
 
     pub fn parse(input: &str) -> Result<EngineSchematic> {
         let state = input
+            .trim()
             .chars()
             .fold(State::initial(), |state, c| state.handle(c));
         let (numbers, symbols) = state.complete();
@@ -1,22 +1,28 @@
 use std::collections::HashSet;
 
-use crate::{read_input_file, PuzzleError, Result};
+use crate::{Answer, PuzzleError, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(4, None)?;
-    let sum = part_one::solve(input.trim())?;
-    println!("Day four part one: sum of card points: {sum}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Card>;
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(4, None)?;
-    let sum = part_two::solve(input.trim())?;
-    println!("Day four part two: sum of card points: {sum}");
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
 }
 
-struct Card {
+pub struct Card {
     id: u32,
     winning_numbers: HashSet<u32>,
     numbers: HashSet<u32>,
@@ -56,17 +62,16 @@ impl Card {
 }
 
 mod part_one {
-    use super::{parser, Result};
+    use super::{Card, Result};
 
-    pub fn solve(input: &str) -> Result<u32> {
-        let cards = parser::parse(input)?;
-        let sum = cards.into_iter().map(|card| card.score()).sum();
+    pub fn solve(cards: &[Card]) -> Result<u32> {
+        let sum = cards.iter().map(|card| card.score()).sum();
         Ok(sum)
     }
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         #[test]
         fn part_one() {
@@ -77,7 +82,8 @@ Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
-            assert_eq!(Ok(13), solve(input.trim()));
+            let cards = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(13), solve(&cards));
         }
     }
 }
@@ -85,13 +91,13 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
 mod part_two {
     use std::collections::HashMap;
 
-    use super::{parser, Card, Result};
+    use super::{Card, Result};
 
-    pub fn solve(input: &str) -> Result<u32> {
-        let cards = parser::parse(input)?
-            .into_iter()
+    pub fn solve(cards: &[Card]) -> Result<u32> {
+        let cards = cards
+            .iter()
             .map(|card| (card.id(), card))
-            .collect::<HashMap<u32, Card>>();
+            .collect::<HashMap<u32, &Card>>();
         let mut sum = cards.len();
         let mut winning_card_ids = cards
             .values()
@@ -101,9 +107,7 @@ mod part_two {
             sum += winning_card_ids.len();
             winning_card_ids = winning_card_ids
                 .into_iter()
-                .flat_map(|card_id| {
-                    return cards.get(&card_id).expect("present").winning_card_ids();
-                })
+                .flat_map(|card_id| cards.get(&card_id).expect("present").winning_card_ids())
                 .collect();
         }
         Ok(sum as u32)
@@ -111,7 +115,7 @@ mod part_two {
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         #[test]
         fn part_two() {
@@ -122,7 +126,8 @@ Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
-            assert_eq!(Ok(30), solve(input.trim()));
+            let cards = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(30), solve(&cards));
         }
     }
 }
@@ -142,19 +147,25 @@ mod parser {
     pub fn parse(input: &str) -> Result<Vec<Card>> {
         input
             .lines()
-            .enumerate()
-            .map(|(line, input)| {
-                let builder = RE_CARD_TOKEN.captures_iter(input).try_fold(
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let builder = RE_CARD_TOKEN.captures_iter(line).try_fold(
                     CardBuilder::new(),
-                    |builder, capture| {
-                        if let Some(token) = capture.get(1) {
-                            builder.process_token(token.as_str())
-                        } else {
-                            Err(PuzzleError::invalid_line_input(line, "failed to parse"))
-                        }
+                    |builder, capture| match capture.get(1) {
+                        Some(token) => builder.process_token(token.as_str()).map_err(|reason| {
+                            PuzzleError::invalid_input_at(input, token.as_str(), &reason)
+                        }),
+                        None => Err(PuzzleError::invalid_input_at(
+                            input,
+                            line,
+                            "failed to parse",
+                        )),
                     },
-                );
-                builder?.build()
+                )?;
+                builder
+                    .build()
+                    .ok_or_else(|| PuzzleError::invalid_input_at(input, line, "incomplete card"))
             })
             .collect()
     }
@@ -182,14 +193,13 @@ mod parser {
             }
         }
 
-        fn process_token(mut self, token: &str) -> Result<Self> {
+        /// Returns the reason a token is rejected.
+        fn process_token(mut self, token: &str) -> std::result::Result<Self, String> {
             if let Ok(number) = token.parse::<u32>() {
                 match self.state {
                     CardBuilderState::GameId => {
                         if let Some(id) = self.id {
-                            return Err(PuzzleError::unexpected(&format!(
-                                "already received game id: {id}"
-                            )));
+                            return Err(format!("already received game id: {id}"));
                         }
                         self.id = Some(number);
                     }
@@ -204,20 +214,20 @@ mod parser {
                 match token {
                     ":" => self.state = CardBuilderState::WinningNumbers,
                     "|" => self.state = CardBuilderState::Numbers,
-                    _ => return Err(PuzzleError::unexpected("invalid token: {token}")),
+                    _ => return Err("invalid token".to_owned()),
                 }
             }
             Ok(self)
         }
 
-        fn build(self) -> Result<Card> {
+        fn build(self) -> Option<Card> {
             match (self.state, self.id) {
-                (CardBuilderState::Numbers, Some(id)) => Ok(Card {
+                (CardBuilderState::Numbers, Some(id)) => Some(Card {
                     id,
                     winning_numbers: self.winning_numbers,
                     numbers: self.numbers,
                 }),
-                _ => Err(PuzzleError::unexpected("invalid parse")),
+                _ => None,
             }
         }
     }
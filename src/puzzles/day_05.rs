@@ -2,20 +2,26 @@ use std::ops::Range;
 
 use log::debug;
 
-use crate::{read_input_file, PuzzleError, Result};
+use crate::{Answer, PuzzleError, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(5, None)?;
-    let location = part_one::solve(input.trim())?;
-    println!("Day five part one: lowest location number: {location}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let location = part_one::solve(input)?;
+        Ok(location.into())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(5, None)?;
-    let location = part_two::solve(input.trim())?;
-    println!("Day five part two: lowest location number: {location}");
-    Ok(())
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let location = part_two::solve(input)?;
+        Ok(location.into())
+    }
 }
 
 enum SeedMode {
@@ -23,31 +29,45 @@ enum SeedMode {
     Range,
 }
 
-struct Almanac {
-    seed_ranges: Vec<Range<u64>>,
+pub struct Almanac {
+    seeds: Vec<u64>,
     component_maps: Vec<ComponentMap>,
 }
 
 impl Almanac {
-    fn seeds(&self) -> impl Iterator<Item = u64> + '_ {
-        self.seed_ranges.iter().flat_map(|range| range.to_owned())
+    fn seed_ranges(&self, seed_mode: SeedMode) -> Result<Vec<Range<u64>>> {
+        match seed_mode {
+            SeedMode::Independent => Ok(self.seeds.iter().map(|&seed| seed..(seed + 1)).collect()),
+            SeedMode::Range => {
+                if !self.seeds.len().is_multiple_of(2) {
+                    return Err(PuzzleError::invalid_input(
+                        "invalid seed values for seed ranges",
+                    ));
+                }
+                Ok(self
+                    .seeds
+                    .chunks(2)
+                    .map(|range| range[0]..(range[0] + range[1]))
+                    .collect())
+            }
+        }
     }
 
-    fn seeds_count(&self) -> u64 {
-        self.seed_ranges
+    fn lowest_location(&self, seed_mode: SeedMode) -> Result<u64> {
+        let seed_ranges = self.seed_ranges(seed_mode)?;
+        debug!("seed ranges: {:?}", seed_ranges);
+        let count = seed_ranges
             .iter()
             .map(|range| range.end - range.start)
-            .sum()
-    }
-
-    fn lowest_location(&self) -> u64 {
-        let count = self.seeds_count();
+            .sum::<u64>();
         let report_progress_interval = count / 100 + 1;
         debug!("finding lowest location of {} seeds", count);
-        self.seeds()
+        Ok(seed_ranges
+            .into_iter()
+            .flatten()
             .enumerate()
             .map(|(progress, id)| {
-                if progress as u64 % report_progress_interval == 0 {
+                if (progress as u64).is_multiple_of(report_progress_interval) {
                     debug!(
                         "{}/{} {}%",
                         progress,
@@ -55,10 +75,10 @@ impl Almanac {
                         (progress as f64 / count as f64 * 100f64) as u32
                     );
                 }
-                return self.component_maps.iter().fold(id, |id, map| map.map(id));
+                self.component_maps.iter().fold(id, |id, map| map.map(id))
             })
             .min()
-            .expect("must have mapping to end of component maps")
+            .expect("must have mapping to end of component maps"))
     }
 }
 
@@ -136,58 +156,58 @@ humidity-to-location map:
 56 93 4"#;
 
 mod part_one {
-    use super::{parser, Result, SeedMode};
+    use super::{Almanac, Result, SeedMode};
 
-    pub fn solve(input: &str) -> Result<u64> {
-        let almanac = parser::parse(input, SeedMode::Independent)?;
-        Ok(almanac.lowest_location())
+    pub fn solve(almanac: &Almanac) -> Result<u64> {
+        almanac.lowest_location(SeedMode::Independent)
     }
 
     #[cfg(test)]
     mod tests {
-        use super::{super::INPUT, solve};
+        use super::{super::parser::parse, super::INPUT, solve};
 
         #[test]
         fn part_one() {
-            assert_eq!(Ok(35), solve(INPUT));
+            let almanac = parse(INPUT).expect("valid parse");
+            assert_eq!(Ok(35), solve(&almanac));
         }
     }
 }
 
 mod part_two {
-    use super::{parser, Result, SeedMode};
+    use super::{Almanac, Result, SeedMode};
 
-    pub fn solve(input: &str) -> Result<u64> {
-        let almanac = parser::parse(input, SeedMode::Range)?;
-        Ok(almanac.lowest_location())
+    pub fn solve(almanac: &Almanac) -> Result<u64> {
+        almanac.lowest_location(SeedMode::Range)
     }
 
     #[cfg(test)]
     mod tests {
-        use super::{super::INPUT, solve};
+        use super::{super::parser::parse, super::INPUT, solve};
 
         #[test]
         fn part_two() {
-            assert_eq!(Ok(46), solve(INPUT));
+            let almanac = parse(INPUT).expect("valid parse");
+            assert_eq!(Ok(46), solve(&almanac));
         }
     }
 }
 
 mod parser {
-    use std::{iter::Iterator, ops::Range};
+    use std::iter::Iterator;
 
     use log::debug;
 
     use crate::parser::HasNumbers;
 
-    use super::{Almanac, ComponentMap, PuzzleError, RangeMap, Result, SeedMode};
+    use super::{Almanac, ComponentMap, PuzzleError, RangeMap, Result};
 
-    pub fn parse(input: &str, seed_mode: SeedMode) -> Result<Almanac> {
-        let (mut almanac_builder, component_builder) = input.lines().enumerate().try_fold(
-            (AlmanacBuilder::new(seed_mode), None::<ComponentMapBuilder>),
-            |(mut almanac_builder, mut component_map_builder), (line, input)| {
-                let input = input.trim();
-                if input.is_empty() {
+    pub fn parse(input: &str) -> Result<Almanac> {
+        let (mut almanac_builder, component_builder) = input.lines().try_fold(
+            (AlmanacBuilder::new(), None::<ComponentMapBuilder>),
+            |(mut almanac_builder, mut component_map_builder), line| {
+                let line = line.trim();
+                if line.is_empty() {
                     if let Some(component_map_builder) = component_map_builder {
                         let component_map = component_map_builder.build()?;
                         almanac_builder.add_component_map(component_map);
@@ -195,21 +215,26 @@ mod parser {
                     }
                     return Ok((almanac_builder, component_map_builder));
                 }
-                let numbers = input.unsigned_numbers().collect::<Vec<u64>>();
+                let numbers = line.unsigned_numbers().collect::<Vec<u64>>();
                 if numbers.is_empty() {
-                    let input = input.split(' ').collect::<Vec<_>>();
-                    if input.len() == 2 {
-                        return Ok((almanac_builder, Some(ComponentMapBuilder::new(input[0]))));
+                    let header = line.split(' ').collect::<Vec<_>>();
+                    if header.len() == 2 {
+                        return Ok((almanac_builder, Some(ComponentMapBuilder::new(header[0]))));
                     }
-                    return Err(PuzzleError::invalid_line_input(line, "invalid map header"));
+                    return Err(PuzzleError::invalid_input_at(
+                        input,
+                        line,
+                        "invalid map header",
+                    ));
                 }
                 if !almanac_builder.has_seeds() {
-                    almanac_builder.add_seeds(numbers)?;
+                    almanac_builder.add_seeds(numbers);
                     return Ok((almanac_builder, component_map_builder));
                 }
                 if let Some(mut component_builder) = component_map_builder.take() {
                     if numbers.len() != 3 {
-                        return Err(PuzzleError::invalid_line_input(
+                        return Err(PuzzleError::invalid_input_at(
+                            input,
                             line,
                             "incorrect map value count",
                         ));
@@ -227,45 +252,21 @@ mod parser {
     }
 
     struct AlmanacBuilder {
-        seed_mode: SeedMode,
-        seed_ranges: Option<Vec<Range<u64>>>,
+        seeds: Option<Vec<u64>>,
         component_maps: Option<Vec<ComponentMap>>,
     }
 
     impl AlmanacBuilder {
-        fn new(seed_mode: SeedMode) -> AlmanacBuilder {
+        fn new() -> AlmanacBuilder {
             AlmanacBuilder {
-                seed_mode,
-                seed_ranges: None,
+                seeds: None,
                 component_maps: None,
             }
         }
 
-        fn add_seeds(&mut self, seeds: Vec<u64>) -> Result<()> {
-            match self.seed_mode {
-                SeedMode::Independent => {
-                    let seed_ranges = seeds.into_iter().map(|seed| (seed..(seed + 1))).collect();
-                    debug!("added seed ranges: {:?}", seed_ranges);
-                    self.seed_ranges = Some(seed_ranges);
-                    Ok(())
-                }
-                SeedMode::Range => {
-                    if seeds.len() % 2 != 0 {
-                        return Err(PuzzleError::unexpected(
-                            "invalid seed values for seed ranges",
-                        ));
-                    }
-                    let mut seed_ranges = vec![];
-                    for index in (0..seeds.len()).step_by(2) {
-                        let start = seeds[index];
-                        let end = start + seeds[index + 1];
-                        seed_ranges.push(start..end);
-                    }
-                    debug!("added seed ranges: {:?}", seed_ranges);
-                    self.seed_ranges = Some(seed_ranges);
-                    Ok(())
-                }
-            }
+        fn add_seeds(&mut self, seeds: Vec<u64>) {
+            debug!("added seeds: {:?}", seeds);
+            self.seeds = Some(seeds);
         }
 
         fn add_component_map(&mut self, map: ComponentMap) {
@@ -279,15 +280,15 @@ mod parser {
         }
 
         fn has_seeds(&self) -> bool {
-            self.seed_ranges.is_some()
+            self.seeds.is_some()
         }
 
         fn build(self) -> Result<Almanac> {
-            match (self.seed_ranges, self.component_maps) {
+            match (self.seeds, self.component_maps) {
                 (Some(seeds), Some(component_maps)) => {
                     debug!("built almanac");
                     Ok(Almanac {
-                        seed_ranges: seeds,
+                        seeds,
                         component_maps,
                     })
                 }
@@ -339,12 +340,15 @@ mod parser {
 
     #[cfg(test)]
     mod tests {
-        use super::{super::INPUT, parse, Almanac, ComponentMap, RangeMap, SeedMode};
+        use super::{
+            super::{SeedMode, INPUT},
+            parse, Almanac, ComponentMap, RangeMap,
+        };
 
         #[test]
         fn parse_seed_mode_independent() {
-            let almanac = parse(INPUT, SeedMode::Independent).expect("failed parse");
-            assert_eq!(vec![79, 14, 55, 13], almanac.seeds().collect::<Vec<_>>());
+            let almanac = parse(INPUT).expect("failed parse");
+            assert_eq!(vec![79, 14, 55, 13], seeds(&almanac, SeedMode::Independent));
             assert_eq!(
                 vec![range(50, 98, 2), range(52, 50, 48)],
                 component_map(&almanac, "seed-to-soil").maps
@@ -357,10 +361,10 @@ mod parser {
 
         #[test]
         fn parse_seed_mode_range() {
-            let almanac = parse(INPUT, SeedMode::Range).expect("failed parse");
-            let mut seeds = seeds_from_range(79, 14);
-            seeds.append(&mut seeds_from_range(55, 13));
-            assert_eq!(seeds, almanac.seeds().collect::<Vec<_>>());
+            let almanac = parse(INPUT).expect("failed parse");
+            let mut expected_seeds = seeds_from_range(79, 14);
+            expected_seeds.append(&mut seeds_from_range(55, 13));
+            assert_eq!(expected_seeds, seeds(&almanac, SeedMode::Range));
             assert_eq!(
                 vec![range(50, 98, 2), range(52, 50, 48)],
                 component_map(&almanac, "seed-to-soil").maps
@@ -371,6 +375,15 @@ mod parser {
             );
         }
 
+        fn seeds(almanac: &Almanac, seed_mode: SeedMode) -> Vec<u64> {
+            almanac
+                .seed_ranges(seed_mode)
+                .expect("valid seed ranges")
+                .into_iter()
+                .flatten()
+                .collect()
+        }
+
         fn component_map<'a>(almanac: &'a Almanac, name: &'a str) -> &'a ComponentMap {
             if let Some(map) = almanac.component_maps.iter().find(|map| map.name == name) {
                 map
@@ -388,7 +401,7 @@ mod parser {
         }
 
         fn seeds_from_range(start: u64, length: u64) -> Vec<u64> {
-            (start..(start + length)).into_iter().collect()
+            (start..(start + length)).collect()
         }
     }
 }
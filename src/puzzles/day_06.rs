@@ -1,27 +1,47 @@
 use std::ops::Range;
 
-use crate::{read_input_file, PuzzleError, Result};
+use crate::{Answer, PuzzleError, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(6, None)?;
-    let product_of_ways_to_win = part_one::solve(input.trim())?;
-    println!("Day five part one: product of ways to win: {product_of_ways_to_win}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<BoatRace>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let product_of_ways_to_win = part_one::solve(input)?;
+        Ok(product_of_ways_to_win.into())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(6, None)?;
-    let product_of_ways_to_win = part_two::solve(input.trim())?;
-    println!("Day five part two: product of ways to win: {product_of_ways_to_win}");
-    Ok(())
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let product_of_ways_to_win = part_two::solve(input)?;
+        Ok(product_of_ways_to_win.into())
+    }
 }
 
-struct BoatRace {
+pub struct BoatRace {
     time: u64,
     record_distance: u64,
 }
 
 impl BoatRace {
+    fn merge(boat_races: &[BoatRace]) -> BoatRace {
+        boat_races.iter().fold(
+            BoatRace {
+                time: 0,
+                record_distance: 0,
+            },
+            |merged, boat_race| BoatRace {
+                time: merged.time * magnitude(boat_race.time) + boat_race.time,
+                record_distance: merged.record_distance * magnitude(boat_race.record_distance)
+                    + boat_race.record_distance,
+            },
+        )
+    }
+
     fn winning_charge_times(&self) -> impl Iterator<Item = u64> + '_ {
         (1..self.time - 1)
             .filter(|charge_time| (self.time - charge_time) * charge_time > self.record_distance)
@@ -52,19 +72,24 @@ impl BoatRace {
     }
 }
 
+fn magnitude(mut number: u64) -> u64 {
+    let mut exp = 0;
+    while number > 0 {
+        number /= 10;
+        exp += 1;
+    }
+    10_u64.pow(exp)
+}
+
 #[cfg(test)]
 const INPUT: &str = r"
 Time:      7  15   30
 Distance:  9  40  200";
 
 mod part_one {
-    use super::{
-        parser::{parse, NumbersMode},
-        Result,
-    };
+    use super::{BoatRace, Result};
 
-    pub fn solve(input: &str) -> Result<usize> {
-        let boat_races = parse(input, NumbersMode::Independent)?;
+    pub fn solve(boat_races: &[BoatRace]) -> Result<usize> {
         let winning_charge_times = boat_races
             .iter()
             .map(|boat_race| boat_race.winning_charge_times());
@@ -76,23 +101,24 @@ mod part_one {
 
     #[cfg(test)]
     mod tests {
-        use super::{super::INPUT, solve};
+        use super::{
+            super::{parser::parse, INPUT},
+            solve,
+        };
 
         #[test]
         fn part_one() {
-            assert_eq!(Ok(288), solve(INPUT.trim()));
+            let boat_races = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(288), solve(&boat_races));
         }
     }
 }
 
 mod part_two {
-    use super::{
-        parser::{parse, NumbersMode},
-        Result,
-    };
+    use super::{BoatRace, Result};
 
-    pub fn solve(input: &str) -> Result<u64> {
-        let boat_races = parse(input, NumbersMode::Merge)?;
+    pub fn solve(boat_races: &[BoatRace]) -> Result<u64> {
+        let boat_races = [BoatRace::merge(boat_races)];
         let winning_charge_ranges = boat_races
             .iter()
             .map(|boat_race| boat_race.winning_charge_range())
@@ -107,11 +133,15 @@ mod part_two {
 
     #[cfg(test)]
     mod tests {
-        use super::{super::INPUT, solve};
+        use super::{
+            super::{parser::parse, INPUT},
+            solve,
+        };
 
         #[test]
         fn part_two() {
-            assert_eq!(Ok(71503), solve(INPUT.trim()));
+            let boat_races = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(71503), solve(&boat_races));
         }
     }
 }
@@ -121,51 +151,45 @@ mod parser {
 
     use super::{BoatRace, PuzzleError, Result};
 
-    pub(super) enum NumbersMode {
-        Independent,
-        Merge,
-    }
-
-    pub(super) fn parse(input: &str, numbers_mode: NumbersMode) -> Result<Vec<BoatRace>> {
+    pub(super) fn parse(input: &str) -> Result<Vec<BoatRace>> {
         let lines = input
             .trim()
             .lines()
             .map(|line| line.trim())
             .collect::<Vec<_>>();
         if lines.len() != 2 {
-            return Err(PuzzleError::invalid_line_input(
-                lines.len(),
+            // the first extra line, or the end of the input when a line is missing
+            let text = lines.get(2).copied().unwrap_or(&input[input.len()..]);
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                text,
                 "invalid number of lines",
             ));
         }
 
         if !lines[0].starts_with("Time:") {
-            return Err(PuzzleError::invalid_line_input(
-                lines.len(),
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                lines[0],
                 "first line must be time",
             ));
         }
 
         if !lines[1].starts_with("Distance:") {
-            return Err(PuzzleError::invalid_line_input(
-                lines.len(),
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                lines[1],
                 "second line must be distance",
             ));
         }
 
-        let (times, distances) = match numbers_mode {
-            NumbersMode::Independent => {
-                (independent_numbers(lines[0]), independent_numbers(lines[1]))
-            }
-            NumbersMode::Merge => (
-                vec![merged_numbers(lines[0])],
-                vec![merged_numbers(lines[1])],
-            ),
-        };
+        let times = lines[0].unsigned_numbers().collect::<Vec<u64>>();
+        let distances = lines[1].unsigned_numbers().collect::<Vec<u64>>();
 
         if times.len() != distances.len() {
-            return Err(PuzzleError::invalid_line_input(
-                lines.len(),
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                lines[1],
                 "time and distance count of entries must match",
             ));
         }
@@ -179,23 +203,4 @@ mod parser {
             })
             .collect())
     }
-
-    fn independent_numbers(input: &str) -> Vec<u64> {
-        input.unsigned_numbers().collect::<Vec<_>>()
-    }
-
-    fn merged_numbers(input: &str) -> u64 {
-        input
-            .unsigned_numbers()
-            .fold(0, |merged, number| merged * magnitude(number) + number)
-    }
-
-    fn magnitude(mut number: u64) -> u64 {
-        let mut exp = 0;
-        while number > 0 {
-            number /= 10;
-            exp += 1;
-        }
-        10_u64.pow(exp)
-    }
 }
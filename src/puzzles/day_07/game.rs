@@ -11,6 +11,15 @@ impl CamelCards {
         CamelCards { hands }
     }
 
+    pub(crate) fn with_jacks_type(&self, jacks_type: JacksType) -> CamelCards {
+        CamelCards::new(
+            self.hands
+                .iter()
+                .map(|hand| Hand::new(hand.bid, hand.cards, jacks_type))
+                .collect(),
+        )
+    }
+
     pub fn winnings(&self) -> usize {
         self.hands
             .iter()
@@ -1,22 +1,45 @@
-use crate::{read_input_file, Result};
+use crate::{Answer, Result, Solution};
 
 mod game;
 mod parser;
 mod part_one;
 mod part_two;
 
-use game::JacksType;
+use game::{CamelCards, JacksType};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(7, None)?;
-    let winnings = part_one::solve(input.trim(), JacksType::Jack)?;
-    println!("Day six part one: camel cards winnings: {winnings}");
-    Ok(())
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = CamelCards;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input, JacksType::Jack)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let winnings = part_one::solve(input)?;
+        Ok(winnings.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let winnings = part_two::solve(input)?;
+        Ok(winnings.into())
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(7, None)?;
-    let winnings = part_two::solve(input.trim(), JacksType::Joker)?;
-    println!("Day six part two: camel cards with jokers winnings: {winnings}");
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::Solver;
+    use crate::Solution;
+
+    #[test]
+    fn parse_locates_errors_in_untrimmed_input() {
+        let err = Solver
+            .parse("\n\n  32T3K 765\n  T55X5 684\n")
+            .expect_err("invalid card");
+        assert_eq!(
+            "invalid input at line 4, column 6: invalid card\n  |\n4 |   T55X5 684\n  |      ^",
+            err.to_string()
+        );
+    }
 }
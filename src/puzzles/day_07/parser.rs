@@ -5,18 +5,10 @@ use super::game::{CamelCards, Card, Cards, Hand, JacksType};
 pub(super) fn parse(input: &str, jacks_type: JacksType) -> Result<CamelCards> {
     let hands = input
         .lines()
-        .enumerate()
-        .filter_map(|(line, input)| {
-            let input = input.trim();
-            if input.is_empty() {
-                None
-            } else {
-                Some((line, input))
-            }
-        })
-        .map(|(line, input)| {
-            let cards_bid = input
-                .trim()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let cards_bid = line
                 .split(' ')
                 .map(|segment| segment.trim())
                 .collect::<Vec<_>>();
@@ -24,19 +16,25 @@ pub(super) fn parse(input: &str, jacks_type: JacksType) -> Result<CamelCards> {
                 || cards_bid[0].len() != 5
                 || cards_bid[1].chars().any(|c| !c.is_ascii_digit())
             {
-                return Err(PuzzleError::invalid_line_input(
+                return Err(PuzzleError::invalid_input_at(
+                    input,
                     line,
                     "invalid cards bid format",
                 ));
             }
 
             let cards = cards_bid[0]
-                .chars()
-                .map(Card::try_from)
+                .char_indices()
+                .map(|(index, c)| {
+                    Card::try_from(c).map_err(|_err| {
+                        let card = &cards_bid[0][index..index + c.len_utf8()];
+                        PuzzleError::invalid_input_at(input, card, "invalid card")
+                    })
+                })
                 .collect::<Result<Vec<_>>>()?;
-            let bid = cards_bid[1]
-                .parse::<usize>()
-                .map_err(|_err| PuzzleError::invalid_line_input(line, "failed to parse bid"))?;
+            let bid = cards_bid[1].parse::<usize>().map_err(|_err| {
+                PuzzleError::invalid_input_at(input, cards_bid[1], "failed to parse bid")
+            })?;
             Hand::try_new(bid, &cards, jacks_type)
         })
         .collect::<Result<Vec<Hand>>>()?;
@@ -119,13 +117,23 @@ QQQJA 483";
         );
     }
 
+    #[test]
+    fn parse_invalid_card() {
+        let input = "32T3K 765\nT55X5 684";
+        let err = parse(input, JacksType::Jack).expect_err("invalid card");
+        assert_eq!(
+            "invalid input at line 2, column 4: invalid card\n  |\n2 | T55X5 684\n  |    ^",
+            err.to_string()
+        );
+    }
+
     #[test]
     fn card_try_from_char() {
         assert_eq!(
             Ok(vec![Ace, King, Queen]),
             "AKQ"
                 .chars()
-                .map(|c| Card::try_from(c))
+                .map(Card::try_from)
                 .collect::<Result<Vec<_>>>()
         );
 
@@ -133,7 +141,7 @@ QQQJA 483";
             Ok(vec![Ten, Nine, Eight, Seven, Two, Three, Four, Five, Six]),
             "T98723456"
                 .chars()
-                .map(|c| Card::try_from(c))
+                .map(Card::try_from)
                 .collect::<Result<Vec<_>>>()
         );
 
@@ -141,7 +149,7 @@ QQQJA 483";
             Err(PuzzleError::invalid_input("invalid card '1'")),
             "T9817"
                 .chars()
-                .map(|c| Card::try_from(c))
+                .map(Card::try_from)
                 .collect::<Result<Vec<_>>>()
         );
 
@@ -149,7 +157,7 @@ QQQJA 483";
             Err(PuzzleError::invalid_input("invalid card 'Z'")),
             "T98Z27"
                 .chars()
-                .map(|c| Card::try_from(c))
+                .map(Card::try_from)
                 .collect::<Result<Vec<_>>>()
         );
     }
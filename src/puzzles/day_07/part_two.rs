@@ -1,14 +1,13 @@
-use super::{game::JacksType, parser::parse};
+use super::game::{CamelCards, JacksType};
 use crate::Result;
 
-pub fn solve(input: &str, jacks_type: JacksType) -> Result<usize> {
-    let camel_cards = parse(input, jacks_type)?;
-    Ok(camel_cards.winnings())
+pub fn solve(camel_cards: &CamelCards) -> Result<usize> {
+    Ok(camel_cards.with_jacks_type(JacksType::Joker).winnings())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{solve, JacksType};
+    use super::{super::parser::parse, solve, JacksType};
 
     const INPUT: &str = r"
 32T3K 765
@@ -19,6 +18,7 @@ QQQJA 483";
 
     #[test]
     fn part_two() {
-        assert_eq!(Ok(5905), solve(INPUT, JacksType::Joker));
+        let camel_cards = parse(INPUT, JacksType::Jack).expect("valid parse");
+        assert_eq!(Ok(5905), solve(&camel_cards));
     }
 }
@@ -1,23 +1,29 @@
 use std::{collections::HashMap, fmt::Display};
 
-use crate::{read_input_file, PuzzleError, Result};
+use crate::{Answer, PuzzleError, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(8, None)?;
-    let steps = part_one::solve(input.trim())?;
-    println!("Day eight part one: steps from AAA to ZZZ: {steps}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = GuidedMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let steps = part_one::solve(input)?;
+        Ok(steps.into())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(8, None)?;
-    let steps = part_two::solve(input.trim())?;
-    println!("Day eight part one: ghost steps from ..A to ..Z: {steps}");
-    Ok(())
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let steps = part_two::solve(input)?;
+        Ok(steps.into())
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-pub(crate) struct GuidedMap {
+pub struct GuidedMap {
     directions: Directions,
     network: HashMap<String, (String, String)>,
 }
@@ -31,16 +37,17 @@ impl GuidedMap {
         }
     }
 
-    fn steps_count(&mut self) -> usize {
+    fn steps_count(&self) -> usize {
         let mut count = 0;
         let mut node = "AAA";
+        let mut directions = self.directions.iter();
         while node != "ZZZ" {
             let (left, right) = self
                 .network
                 .get(node)
                 .unwrap_or_else(|| panic!("node '{node}' not found in the map"));
             count += 1;
-            node = match self.directions.next() {
+            node = match directions.next().expect("directions repeat") {
                 Direction::Left => left,
                 Direction::Right => right,
             };
@@ -48,7 +55,7 @@ impl GuidedMap {
         count
     }
 
-    fn ghost_steps_count(&mut self) -> usize {
+    fn ghost_steps_count(&self) -> usize {
         let nodes = self
             .network
             .keys()
@@ -57,13 +64,13 @@ impl GuidedMap {
         let mut steps_to_z_count = nodes
             .iter()
             .map(|start_node| {
-                self.directions.reset();
+                let mut directions = self.directions.iter();
                 let mut count = 0;
                 let mut node: &str = start_node.as_ref();
                 while !node.ends_with('Z') {
                     count += 1;
                     let (left, right) = self.network.get(node).expect("node in map");
-                    node = match self.directions.next() {
+                    node = match directions.next().expect("directions repeat") {
                         Direction::Left => left,
                         Direction::Right => right,
                     };
@@ -90,33 +97,15 @@ impl GuidedMap {
 #[derive(Debug, PartialEq, Eq)]
 struct Directions {
     directions: Vec<Direction>,
-    direction_next: usize,
-    iteration: usize,
 }
 
 impl Directions {
     fn new(directions: Vec<Direction>) -> Directions {
-        Directions {
-            directions,
-            direction_next: 0,
-            iteration: 0,
-        }
-    }
-
-    fn next(&mut self) -> Direction {
-        if self.direction_next == self.directions.len() {
-            self.direction_next = 0;
-            self.iteration += 1;
-        }
-
-        let direction = self.directions[self.direction_next];
-        self.direction_next += 1;
-        direction
+        Directions { directions }
     }
 
-    fn reset(&mut self) {
-        self.direction_next = 0;
-        self.iteration = 0;
+    fn iter(&self) -> impl Iterator<Item = Direction> + '_ {
+        self.directions.iter().copied().cycle()
     }
 }
 
@@ -183,10 +172,9 @@ XXX = (XXX, XXX)";
 }
 
 mod part_one {
-    use super::{parser::parse, Result};
+    use super::{GuidedMap, Result};
 
-    pub fn solve(input: &str) -> Result<usize> {
-        let mut map = parse(input)?;
+    pub fn solve(map: &GuidedMap) -> Result<usize> {
         Ok(map.steps_count())
     }
 
@@ -194,39 +182,38 @@ mod part_one {
     pub mod tests {
 
         use super::{
+            super::parser::parse,
             super::tests::{INPUT_SIX_STEPS, INPUT_TWO_STEPS},
-            parse,
         };
 
         #[test]
         fn part_one_2_steps() {
-            let mut map = parse(INPUT_TWO_STEPS).expect("valid parse");
+            let map = parse(INPUT_TWO_STEPS).expect("valid parse");
             assert_eq!(2, map.steps_count())
         }
 
         #[test]
         fn part_one_6_steps() {
-            let mut map = parse(INPUT_SIX_STEPS).expect("valid parse");
+            let map = parse(INPUT_SIX_STEPS).expect("valid parse");
             assert_eq!(6, map.steps_count())
         }
     }
 }
 
 mod part_two {
-    use super::{parser::parse, Result};
+    use super::{GuidedMap, Result};
 
-    pub fn solve(input: &str) -> Result<usize> {
-        let mut map = parse(input)?;
+    pub fn solve(map: &GuidedMap) -> Result<usize> {
         Ok(map.ghost_steps_count())
     }
 
     #[cfg(test)]
     pub mod tests {
-        use super::{super::tests::GHOST_STEPS, parse};
+        use super::super::{parser::parse, tests::GHOST_STEPS};
 
         #[test]
         fn part_two() {
-            let mut map = parse(GHOST_STEPS).expect("valid parse");
+            let map = parse(GHOST_STEPS).expect("valid parse");
             assert_eq!(6, map.ghost_steps_count())
         }
     }
@@ -248,32 +235,31 @@ mod parser {
     pub(super) fn parse(input: &str) -> Result<GuidedMap> {
         let (directions, network) = input
             .lines()
-            .enumerate()
-            .filter_map(filter_empty_line_input)
-            .try_fold(
-                (None, HashMap::new()),
-                |(directions, mut network), (line, input)| {
-                    if let Some(directions) = directions {
-                        let captures = RE_CARD_TOKEN.captures(input).ok_or_else(|| {
-                            PuzzleError::invalid_line_input(line, "invalid node steps format")
-                        })?;
-                        let node = captures.name("node").expect("captures succeeded").as_str();
-                        let left = captures.name("left").expect("captures succeeded").as_str();
-                        let right = captures.name("right").expect("captures succeeded").as_str();
-                        network.insert(node.to_owned(), (left.to_owned(), right.to_owned()));
-                        Ok((Some(directions), network))
-                    } else {
-                        match input
-                            .chars()
-                            .map(Direction::try_from)
-                            .collect::<Result<Vec<_>>>()
-                        {
-                            Ok(directions) => Ok((Some(directions), network)),
-                            Err(err) => Err(err),
-                        }
-                    }
-                },
-            )
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .try_fold((None, HashMap::new()), |(directions, mut network), line| {
+                if let Some(directions) = directions {
+                    let captures = RE_CARD_TOKEN.captures(line).ok_or_else(|| {
+                        PuzzleError::invalid_input_at(input, line, "invalid node steps format")
+                    })?;
+                    let node = captures.name("node").expect("captures succeeded").as_str();
+                    let left = captures.name("left").expect("captures succeeded").as_str();
+                    let right = captures.name("right").expect("captures succeeded").as_str();
+                    network.insert(node.to_owned(), (left.to_owned(), right.to_owned()));
+                    Ok((Some(directions), network))
+                } else {
+                    let directions = line
+                        .char_indices()
+                        .map(|(index, c)| {
+                            Direction::try_from(c).map_err(|_err| {
+                                let direction = &line[index..index + c.len_utf8()];
+                                PuzzleError::invalid_input_at(input, direction, "invalid direction")
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok((Some(directions), network))
+                }
+            })
             .and_then(|(directions, network)| {
                 if let Some(directions) = directions {
                     Ok((directions, network))
@@ -284,15 +270,6 @@ mod parser {
         Ok(GuidedMap::new(directions, network))
     }
 
-    fn filter_empty_line_input((line, input): (usize, &str)) -> Option<(usize, &str)> {
-        let input = input.trim();
-        if input.is_empty() {
-            None
-        } else {
-            Some((line, input))
-        }
-    }
-
     #[cfg(test)]
     mod tests {
 
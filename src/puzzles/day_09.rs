@@ -2,24 +2,30 @@ use std::cmp::Ordering;
 
 use num::Signed;
 
-use crate::{read_input_file, Result};
+use crate::{Answer, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(9, None)?;
-    let sum = part_one::solve(input.trim())?;
-    println!("Day nine part one: sum of next forecasted values: {sum}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = OASISReport;
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(9, None)?;
-    let sum = part_two::solve(input.trim())?;
-    println!("Day nine part one: sum of prior forecasted values: {sum}");
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
 }
 
 #[derive(PartialEq, Debug)]
-struct OASISReport {
+pub struct OASISReport {
     report: Vec<Vec<i64>>,
 }
 
@@ -42,7 +48,7 @@ impl OASISReport {
             .collect()
     }
 
-    fn forecast_next_values(values: &[i64]) -> i64 {
+    pub(crate) fn forecast_next_values(values: &[i64]) -> i64 {
         let differences = OASISReport::differences(values);
         let last_value = values[values.len() - 1];
         if differences.iter().any(|difference| *difference != 0) {
@@ -147,16 +153,15 @@ mod tests {
 }
 
 mod part_one {
-    use super::{parser::parse, Result};
+    use super::{OASISReport, Result};
 
-    pub fn solve(input: &str) -> Result<i64> {
-        let report = parse(input)?;
+    pub fn solve(report: &OASISReport) -> Result<i64> {
         Ok(report.forecast_next().iter().copied().sum())
     }
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         const INPUT: &str = r"
 0 3 6 9 12 15
@@ -165,22 +170,22 @@ mod part_one {
 
         #[test]
         fn part_one() {
-            assert_eq!(Ok(114), solve(INPUT));
+            let report = parse(INPUT).expect("valid parse");
+            assert_eq!(Ok(114), solve(&report));
         }
     }
 }
 
 mod part_two {
-    use super::{parser::parse, Result};
+    use super::{OASISReport, Result};
 
-    pub fn solve(input: &str) -> Result<i64> {
-        let report = parse(input)?;
+    pub fn solve(report: &OASISReport) -> Result<i64> {
         Ok(report.forecast_prior().iter().copied().sum())
     }
 
     #[cfg(test)]
     mod tests {
-        use super::solve;
+        use super::{super::parser::parse, solve};
 
         const INPUT: &str = r"
 0 3 6 9 12 15
@@ -189,7 +194,8 @@ mod part_two {
 
         #[test]
         fn part_one() {
-            assert_eq!(Ok(2), solve(INPUT));
+            let report = parse(INPUT).expect("valid parse");
+            assert_eq!(Ok(2), solve(&report));
         }
     }
 }
@@ -1,10 +1,12 @@
 use std::fmt::{Debug, Display};
 
+use log::debug;
+
 pub(crate) mod tiles;
 use tiles::Tiles;
 
 #[derive(PartialEq)]
-pub(crate) struct Map {
+pub struct Map {
     tiles: Tiles,
 }
 
@@ -14,12 +16,12 @@ impl Map {
     }
 
     pub(crate) fn steps_to_furthest_point(&self) -> u64 {
-        (self.tiles.path_iter().count() as u64 + 1) / 2
+        (self.tiles.path_iter().count() as u64).div_ceil(2)
     }
 
     pub(crate) fn enclosed_tile_count(&self) -> u64 {
-        println!("{self}");
-        self.tiles.enclosed_tile_iter().count() as u64
+        debug!("{self}");
+        self.tiles.enclosed_tile_count()
     }
 }
 
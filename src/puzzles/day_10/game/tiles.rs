@@ -1,11 +1,11 @@
-use crate::{PuzzleError, Result};
+use crate::{polygon, PuzzleError, Result};
 
 use std::{collections::HashSet, fmt::Debug, fmt::Display};
 
 mod tiles_iter;
 use tiles_iter::{EnclosedTileIter, PathIter};
 
-const MIN_TILES_HEIGHT_WIDTH: usize = 3;
+pub(crate) const MIN_TILES_HEIGHT_WIDTH: usize = 3;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct TilePosition {
@@ -119,14 +119,24 @@ pub(crate) struct Tiles {
 }
 
 impl Tiles {
-    pub fn path_iter(&self) -> PathIter {
+    pub fn path_iter(&self) -> PathIter<'_> {
         PathIter::new(self)
     }
 
-    pub fn enclosed_tile_iter(&self) -> EnclosedTileIter {
+    pub fn enclosed_tile_iter(&self) -> EnclosedTileIter<'_> {
         EnclosedTileIter::new(self)
     }
 
+    /// The tiles enclosed by the pipe loop, counted with Pick's theorem over
+    /// the loop's tiles as polygon vertices.
+    pub fn enclosed_tile_count(&self) -> u64 {
+        let vertices = self
+            .path_iter()
+            .map(|tile_pos| (tile_pos.row as i64, tile_pos.column as i64))
+            .collect::<Vec<_>>();
+        polygon::interior_points(&vertices) as u64
+    }
+
     pub fn write(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let pipe_path = self.path_iter().collect::<HashSet<_>>();
         let enclosed_tiles = self.enclosed_tile_iter().collect::<HashSet<_>>();
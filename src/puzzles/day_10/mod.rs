@@ -1,20 +1,28 @@
-use crate::{read_input_file, Result};
+use crate::{Answer, Result, Solution};
 
 mod game;
 mod parser;
 mod part_one;
 mod part_two;
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(10, None)?;
-    let steps = part_one::solve(input.trim())?;
-    println!("Day ten part one: steps to furthest point: {steps}");
-    Ok(())
-}
+use game::Map;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let steps = part_one::solve(input)?;
+        Ok(steps.into())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(10, None)?;
-    let count = part_two::solve(input.trim())?;
-    println!("Day ten part two: count of enclosed tiles: {count}");
-    Ok(())
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let count = part_two::solve(input)?;
+        Ok(count.into())
+    }
 }
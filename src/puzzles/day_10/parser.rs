@@ -1,28 +1,56 @@
 use super::game::{
-    tiles::{Tile, Tiles},
+    tiles::{Tile, Tiles, MIN_TILES_HEIGHT_WIDTH},
     Map,
 };
 use crate::result::{PuzzleError, Result};
 
 pub fn parse(input: &str) -> Result<Map> {
-    Ok(Map::new(Tiles::try_from(
-        input
-            .trim()
-            .lines()
-            .map(|input| {
-                input
-                    .trim()
-                    .chars()
-                    .map(Tile::try_from)
-                    .collect::<Result<Vec<_>>>()
+    let mut rows = vec![];
+    let mut first_line = None;
+    let mut start = None;
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let row = line
+            .char_indices()
+            .map(|(index, c)| {
+                let tile = &line[index..index + c.len_utf8()];
+                let parsed = Tile::try_from(c)
+                    .map_err(|_| PuzzleError::invalid_input_at(input, tile, "invalid map tile"))?;
+                if parsed.is_start() && start.replace(tile).is_some() {
+                    return Err(PuzzleError::invalid_input_at(
+                        input,
+                        tile,
+                        "too many start tiles",
+                    ));
+                }
+                Ok(parsed)
             })
-            .collect::<Result<Vec<Vec<_>>>>()
-            .map_err(|_| PuzzleError::invalid_input("invalid map tiles"))?,
-    )?))
+            .collect::<Result<Vec<_>>>()?;
+        let first_line = *first_line.get_or_insert(line);
+        if row.len() != first_line.chars().count() {
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                line,
+                "map rows must have the same width",
+            ));
+        }
+        rows.push(row);
+    }
+    if let Some(first_line) =
+        first_line.filter(|line| line.chars().count() < MIN_TILES_HEIGHT_WIDTH)
+    {
+        return Err(PuzzleError::invalid_input_at(
+            input,
+            first_line,
+            "the map is too narrow",
+        ));
+    }
+    Ok(Map::new(Tiles::try_from(rows)?))
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::PuzzleError;
+
     #[test]
     fn parse() {
         use super::{Map, Tile::*, Tiles};
@@ -46,4 +74,26 @@ LJ.LJ";
 
         assert_eq!(Ok(expected_map), super::parse(INPUT));
     }
+
+    #[test]
+    fn parse_invalid_map() {
+        let located = |input| match super::parse(input) {
+            Err(PuzzleError::InvalidInputAt { span, reason, .. }) => {
+                Some((span.line, span.column, reason))
+            }
+            _ => None,
+        };
+        assert_eq!(
+            Some((2, 1, "map rows must have the same width".to_owned())),
+            located("7-F7-\n.FJ|\nSJLL7")
+        );
+        assert_eq!(
+            Some((3, 4, "too many start tiles".to_owned())),
+            located("7-F7-\n.FJ|7\nSJLS7")
+        );
+        assert_eq!(
+            Some((1, 1, "the map is too narrow".to_owned())),
+            located("7-\nSJ\nLJ")
+        );
+    }
 }
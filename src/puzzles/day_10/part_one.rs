@@ -1,13 +1,12 @@
-use super::{parser::parse, Result};
+use super::{game::Map, Result};
 
-pub(crate) fn solve(input: &str) -> Result<u64> {
-    let map = parse(input)?;
+pub(crate) fn solve(map: &Map) -> Result<u64> {
     Ok(map.steps_to_furthest_point())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::{super::parser::parse, solve};
 
     #[test]
     fn part_one() {
@@ -17,6 +16,7 @@ mod tests {
 SJLL7
 |F--J
 LJ.LJ";
-        assert_eq!(Ok(8), solve(INPUT));
+        let map = parse(INPUT).expect("valid parse");
+        assert_eq!(Ok(8), solve(&map));
     }
 }
@@ -1,13 +1,12 @@
-use super::{parser::parse, Result};
+use super::{game::Map, Result};
 
-pub(crate) fn solve(input: &str) -> Result<u64> {
-    let map = parse(input)?;
+pub(crate) fn solve(map: &Map) -> Result<u64> {
     Ok(map.enclosed_tile_count())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::{super::parser::parse, solve};
 
     #[test]
     fn part_two_simple() {
@@ -21,7 +20,8 @@ mod tests {
 .|..|.|..|.
 .L--J.L--J.
 ...........";
-        assert_eq!(Ok(4), solve(INPUT));
+        let map = parse(INPUT).expect("valid parse");
+        assert_eq!(Ok(4), solve(&map));
     }
 
     #[test]
@@ -37,6 +37,7 @@ L--J.L7...LJS7F-7L7.
 .....|FJLJ|FJ|F7|.LJ
 ....FJL-7.||.||||...
 ....L---J.LJ.LJLJ...";
-        assert_eq!(Ok(8), solve(INPUT));
+        let map = parse(INPUT).expect("valid parse");
+        assert_eq!(Ok(8), solve(&map));
     }
 }
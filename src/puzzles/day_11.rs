@@ -1,22 +1,29 @@
 use std::str::FromStr;
 
-use super::{read_input_file, PuzzleError, Result};
+use super::{Answer, PuzzleError, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    let input = read_input_file(11, None)?;
-    let sum = part_one::solve(input.trim())?;
-    println!("Day eleven part one: sum of galaxy path lengths: {sum}");
-    Ok(())
-}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Universe;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
 
-pub fn run_part_two() -> Result<()> {
-    let input = read_input_file(11, None)?;
-    let sum = part_two::solve(input.trim())?;
-    println!("Day eleven part one: sum of older galaxy path lengths: {sum}");
-    Ok(())
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
 }
 
-struct Universe {
+#[derive(Clone)]
+pub struct Universe {
     galaxies: Vec<(usize, usize)>,
     last_row: usize,
     last_column: usize,
@@ -93,17 +100,17 @@ impl FromStr for Universe {
             .trim()
             .lines()
             .enumerate()
-            .flat_map(|(row, input)| {
-                input
-                    .trim()
-                    .chars()
+            .flat_map(|(row, line)| {
+                let line = line.trim();
+                line.char_indices()
                     .enumerate()
-                    .map(move |(column, input)| match input {
+                    .map(move |(column, (offset, c))| match c {
                         '#' => Ok(Some((row, column))),
                         '.' => Ok(None),
-                        other => Err(PuzzleError::invalid_line_input(
-                            row,
-                            &format!("invalid character '{}'", other),
+                        other => Err(PuzzleError::invalid_input_at(
+                            input,
+                            &line[offset..offset + other.len_utf8()],
+                            "invalid character",
                         )),
                     })
             })
@@ -144,13 +151,15 @@ mod tests {
 
     #[test]
     fn part_two_solve() {
-        let sum = super::part_two::solve(INPUT);
-        assert_eq!(sum, Ok(374));
+        let universe = Universe::from_str(INPUT).expect("valid input");
+        let sum = super::part_two::solve(&universe);
+        assert_eq!(sum, Ok(82000210));
     }
 
     #[test]
     fn part_one_solve() {
-        let sum = super::part_one::solve(INPUT);
+        let universe = Universe::from_str(INPUT).expect("valid input");
+        let sum = super::part_one::solve(&universe);
         assert_eq!(sum, Ok(374));
     }
 
@@ -238,8 +247,8 @@ mod tests {
 mod part_one {
     use super::{Result, Universe};
 
-    pub fn solve(input: &str) -> Result<u64> {
-        let mut universe: Universe = input.parse()?;
+    pub fn solve(universe: &Universe) -> Result<u64> {
+        let mut universe = universe.clone();
         universe.expand(2)?;
         let sum = universe
             .galaxies_paths_iter()
@@ -252,8 +261,8 @@ mod part_one {
 mod part_two {
     use super::{Result, Universe};
 
-    pub fn solve(input: &str) -> Result<u64> {
-        let mut universe: Universe = input.parse()?;
+    pub fn solve(universe: &Universe) -> Result<u64> {
+        let mut universe = universe.clone();
         universe.expand(1_000_000)?;
         let sum = universe
             .galaxies_paths_iter()
@@ -1,9 +1,254 @@
-use super::{PuzzleError, Result};
+use std::collections::HashMap;
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<ConditionRecord>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConditionRecord {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl ConditionRecord {
+    fn new(springs: Vec<Spring>, groups: Vec<usize>) -> ConditionRecord {
+        ConditionRecord { springs, groups }
+    }
+
+    /// The record repeated `copies` times, with the springs joined by an
+    /// unknown spring.
+    fn unfold(&self, copies: usize) -> ConditionRecord {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * copies);
+        for copy in 0..copies {
+            if copy > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        ConditionRecord::new(springs, self.groups.repeat(copies))
+    }
+
+    fn arrangements(&self) -> u64 {
+        let mut memo = HashMap::new();
+        self.count(0, 0, &mut memo)
+    }
+
+    /// Counts the arrangements of the springs from `spring` on that match the
+    /// groups from `group` on.
+    fn count(&self, spring: usize, group: usize, memo: &mut HashMap<(usize, usize), u64>) -> u64 {
+        if group == self.groups.len() {
+            let any_damaged =
+                self.springs[spring.min(self.springs.len())..].contains(&Spring::Damaged);
+            return if any_damaged { 0 } else { 1 };
+        }
+        if spring >= self.springs.len() {
+            return 0;
+        }
+        if let Some(&count) = memo.get(&(spring, group)) {
+            return count;
+        }
+
+        let mut count = 0;
+        if self.springs[spring] != Spring::Damaged {
+            count += self.count(spring + 1, group, memo);
+        }
+        if self.springs[spring] != Spring::Operational && self.fits_group(spring, group) {
+            count += self.count(spring + self.groups[group] + 1, group + 1, memo);
+        }
+        memo.insert((spring, group), count);
+        count
+    }
+
+    fn fits_group(&self, spring: usize, group: usize) -> bool {
+        let end = spring + self.groups[group];
+        end <= self.springs.len()
+            && !self.springs[spring..end].contains(&Spring::Operational)
+            && self.springs.get(end) != Some(&Spring::Damaged)
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, ConditionRecord};
+
+    fn record(input: &str) -> ConditionRecord {
+        parse(input).expect("valid parse").remove(0)
+    }
+
+    #[test]
+    fn arrangements() {
+        assert_eq!(1, record("???.### 1,1,3").arrangements());
+        assert_eq!(4, record(".??..??...?##. 1,1,3").arrangements());
+        assert_eq!(10, record("?###???????? 3,2,1").arrangements());
+    }
+
+    #[test]
+    fn unfolded_arrangements() {
+        assert_eq!(1, record("???.### 1,1,3").unfold(5).arrangements());
+        assert_eq!(
+            16384,
+            record(".??..??...?##. 1,1,3").unfold(5).arrangements()
+        );
+        assert_eq!(
+            506250,
+            record("?###???????? 3,2,1").unfold(5).arrangements()
+        );
+    }
+
+    #[test]
+    fn unfold() {
+        assert_eq!(
+            record("???.###????.### 1,1,3,1,1,3"),
+            record("???.### 1,1,3").unfold(2)
+        );
+    }
+}
+
+mod part_one {
+    use super::{ConditionRecord, Result};
+
+    pub fn solve(records: &[ConditionRecord]) -> Result<u64> {
+        Ok(records.iter().map(ConditionRecord::arrangements).sum())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, solve};
+
+        const INPUT: &str = r"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        #[test]
+        fn part_one() {
+            let records = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(21), solve(&records));
+        }
+    }
+}
+
+mod part_two {
+    use super::{ConditionRecord, Result};
+
+    const FOLDS: usize = 5;
+
+    pub fn solve(records: &[ConditionRecord]) -> Result<u64> {
+        Ok(records
+            .iter()
+            .map(|record| record.unfold(FOLDS).arrangements())
+            .sum())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, solve};
+
+        const INPUT: &str = r"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        #[test]
+        fn part_two() {
+            let records = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(525152), solve(&records));
+        }
+    }
+}
+
+mod parser {
+    use super::{ConditionRecord, Result, Spring};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Vec<ConditionRecord>> {
+        input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (springs, groups) = line.split_once(' ').ok_or_else(|| {
+                    PuzzleError::invalid_input_at(input, line, "missing damaged spring groups")
+                })?;
+                let springs = springs
+                    .char_indices()
+                    .map(|(index, c)| match c {
+                        '.' => Ok(Spring::Operational),
+                        '#' => Ok(Spring::Damaged),
+                        '?' => Ok(Spring::Unknown),
+                        _ => Err(PuzzleError::invalid_input_at(
+                            input,
+                            &springs[index..index + c.len_utf8()],
+                            "invalid spring condition",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let groups = groups
+                    .split(',')
+                    .map(|group| {
+                        group.trim().parse::<usize>().map_err(|_| {
+                            PuzzleError::invalid_input_at(input, group, "invalid group size")
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ConditionRecord::new(springs, groups))
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{parse, ConditionRecord, Spring::*};
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_input() {
+            assert_eq!(
+                Ok(vec![ConditionRecord::new(
+                    vec![Unknown, Damaged, Operational],
+                    vec![1, 1]
+                )]),
+                parse("?#. 1,1")
+            );
+        }
+
+        #[test]
+        fn parse_invalid_spring() {
+            assert!(matches!(
+                parse("?#x 1"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+        }
+    }
 }
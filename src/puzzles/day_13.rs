@@ -1,9 +1,237 @@
-use super::{PuzzleError, Result};
+use crate::{Answer, Grid, PuzzleError, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Pattern>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let summary = part_one::solve(input)?;
+        Ok(summary.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let summary = part_two::solve(input)?;
+        Ok(summary.into())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Reflection {
+    /// A vertical mirror line with this many columns to its left.
+    Vertical(usize),
+    /// A horizontal mirror line with this many rows above it.
+    Horizontal(usize),
+}
+
+impl Reflection {
+    fn summary(&self) -> usize {
+        match self {
+            Reflection::Vertical(columns) => *columns,
+            Reflection::Horizontal(rows) => 100 * rows,
+        }
+    }
+}
+
+/// A pattern of ash and rocks with each row and column kept as a bitmask of
+/// its rocks.
+#[derive(Debug, PartialEq)]
+pub struct Pattern {
+    rows: Vec<u32>,
+    columns: Vec<u32>,
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+impl Pattern {
+    fn new(grid: &Grid<bool>) -> Pattern {
+        let mask = |cells: &mut dyn Iterator<Item = &bool>| {
+            cells.fold(0, |mask, &rock| (mask << 1) | u32::from(rock))
+        };
+        let rows = grid.rows().map(|row| mask(&mut row.iter())).collect();
+        let columns = (0..grid.width())
+            .map(|column| mask(&mut grid.column(column)))
+            .collect();
+        Pattern { rows, columns }
+    }
+
+    /// Finds the mirror line where the reflected rows or columns differ in
+    /// exactly `smudges` cells.
+    fn reflection(&self, smudges: u32) -> Option<Reflection> {
+        Pattern::mirror(&self.columns, smudges)
+            .map(Reflection::Vertical)
+            .or_else(|| Pattern::mirror(&self.rows, smudges).map(Reflection::Horizontal))
+    }
+
+    fn mirror(lines: &[u32], smudges: u32) -> Option<usize> {
+        (1..lines.len()).find(|&mirror| {
+            let before = lines[..mirror].iter().rev();
+            let after = lines[mirror..].iter();
+            before
+                .zip(after)
+                .map(|(before, after)| (before ^ after).count_ones())
+                .sum::<u32>()
+                == smudges
+        })
+    }
+}
+
+fn summarize(patterns: &[Pattern], smudges: u32) -> Result<usize> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(index, pattern)| {
+            pattern
+                .reflection(smudges)
+                .map(|reflection| reflection.summary())
+                .ok_or_else(|| {
+                    PuzzleError::invalid_input(&format!(
+                        "pattern {} has no reflection with {smudges} smudges",
+                        index + 1
+                    ))
+                })
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    pub(super) const INPUT: &str = r"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    use super::{parser::parse, Reflection};
+
+    #[test]
+    fn reflections() {
+        let patterns = parse(INPUT.trim()).expect("valid parse");
+        assert_eq!(Some(Reflection::Vertical(5)), patterns[0].reflection(0));
+        assert_eq!(Some(Reflection::Horizontal(4)), patterns[1].reflection(0));
+        assert_eq!(Some(Reflection::Horizontal(3)), patterns[0].reflection(1));
+        assert_eq!(Some(Reflection::Horizontal(1)), patterns[1].reflection(1));
+    }
+}
+
+mod part_one {
+    use super::{summarize, Pattern, Result};
+
+    pub fn solve(patterns: &[Pattern]) -> Result<usize> {
+        summarize(patterns, 0)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let patterns = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(405), solve(&patterns));
+        }
+    }
+}
+
+mod part_two {
+    use super::{summarize, Pattern, Result};
+
+    pub fn solve(patterns: &[Pattern]) -> Result<usize> {
+        summarize(patterns, 1)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let patterns = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(400), solve(&patterns));
+        }
+    }
+}
+
+mod parser {
+    use super::{Pattern, Result};
+    use crate::{parser::blocks, Grid, PuzzleError};
+
+    const MAX_PATTERN_SIZE: usize = u32::BITS as usize;
+
+    pub fn parse(input: &str) -> Result<Vec<Pattern>> {
+        blocks(input)
+            .into_iter()
+            .map(|block| {
+                let grid = Grid::parse_block(input, block, |c| match c {
+                    '#' => Some(true),
+                    '.' => Some(false),
+                    _ => None,
+                })?;
+                // locate the first row past either limit
+                let mut rows = block.lines().map(str::trim).filter(|row| !row.is_empty());
+                let oversized = if grid.width() > MAX_PATTERN_SIZE {
+                    rows.next().map(|row| (row, "pattern is too wide"))
+                } else if grid.height() > MAX_PATTERN_SIZE {
+                    rows.nth(MAX_PATTERN_SIZE)
+                        .map(|row| (row, "pattern is too tall"))
+                } else {
+                    None
+                };
+                if let Some((row, reason)) = oversized {
+                    return Err(PuzzleError::invalid_input_at(input, row, reason));
+                }
+                Ok(Pattern::new(&grid))
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{parse, Pattern, MAX_PATTERN_SIZE};
+
+        #[test]
+        fn parse_input() {
+            assert_eq!(
+                Ok(vec![
+                    Pattern {
+                        rows: vec![0b10, 0b01],
+                        columns: vec![0b10, 0b01],
+                    },
+                    Pattern {
+                        rows: vec![0b111],
+                        columns: vec![0b1, 0b1, 0b1],
+                    }
+                ]),
+                parse("#.\n.#\n\n###")
+            );
+        }
+
+        #[test]
+        fn parse_oversized_pattern() {
+            let wide = format!("#.\n.#\n\n{}\n{0}", "#".repeat(MAX_PATTERN_SIZE + 1));
+            let err = parse(&wide).expect_err("too wide").to_string();
+            assert!(err.starts_with("invalid input at line 4, column 1: pattern is too wide\n"));
+
+            let tall = "#.\n".repeat(MAX_PATTERN_SIZE + 1);
+            let err = parse(&tall).expect_err("too tall").to_string();
+            let line = MAX_PATTERN_SIZE + 1;
+            assert!(err.starts_with(&format!(
+                "invalid input at line {line}, column 1: pattern is too tall\n"
+            )));
+            assert!(err.ends_with(&format!("{line} | #.\n   | ^^")));
+        }
+    }
 }
@@ -1,9 +1,240 @@
-use super::{PuzzleError, Result};
+use std::{collections::HashMap, fmt::Display};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Direction, Grid, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Grid<Rock>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let load = part_one::solve(input)?;
+        Ok(load.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let load = part_two::solve(input)?;
+        Ok(load.into())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        };
+        write!(f, "{c}")
+    }
+}
+
+const SPIN_CYCLE: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+/// Rolls every round rock as far as it goes in `direction`.
+pub fn tilt(platform: &mut Grid<Rock>, direction: Direction) {
+    let (width, height) = (platform.width(), platform.height());
+    let (lines, length) = match direction {
+        Direction::North | Direction::South => (width, height),
+        Direction::East | Direction::West => (height, width),
+    };
+    // index 0 of a line is the edge the rocks roll towards
+    let position = |line: usize, index: usize| match direction {
+        Direction::North => (index, line),
+        Direction::South => (height - 1 - index, line),
+        Direction::West => (line, index),
+        Direction::East => (line, width - 1 - index),
+    };
+    for line in 0..lines {
+        let mut free = 0;
+        for index in 0..length {
+            match platform[position(line, index)] {
+                Rock::Cube => free = index + 1,
+                Rock::Round => {
+                    if free != index {
+                        platform[position(line, free)] = Rock::Round;
+                        platform[position(line, index)] = Rock::Empty;
+                    }
+                    free += 1;
+                }
+                Rock::Empty => {}
+            }
+        }
+    }
+}
+
+pub fn spin_cycle(platform: &mut Grid<Rock>) {
+    for direction in SPIN_CYCLE {
+        tilt(platform, direction);
+    }
+}
+
+/// The platform after `cycles` spin cycles, skipping ahead once a platform
+/// state repeats.
+pub fn spin_cycles(platform: &Grid<Rock>, cycles: usize) -> Grid<Rock> {
+    let mut platform = platform.clone();
+    let mut seen = HashMap::new();
+    let mut cycle = 0;
+    while cycle < cycles {
+        if let Some(start) = seen.insert(platform.clone(), cycle) {
+            let period = cycle - start;
+            let remaining = (cycles - cycle) % period;
+            for _ in 0..remaining {
+                spin_cycle(&mut platform);
+            }
+            return platform;
+        }
+        spin_cycle(&mut platform);
+        cycle += 1;
+    }
+    platform
+}
+
+/// The total load on the north support beams.
+pub fn north_load(platform: &Grid<Rock>) -> usize {
+    platform
+        .find(|&rock| rock == Rock::Round)
+        .map(|(row, _)| platform.height() - row)
+        .sum()
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, spin_cycle, tilt};
+    use crate::Direction;
+
+    pub(super) const INPUT: &str = r"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn tilt_north() {
+        let mut platform = parse(INPUT.trim()).expect("valid parse");
+        tilt(&mut platform, Direction::North);
+        let expected = r"
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+        assert_eq!(expected.trim_start(), platform.to_string().trim_end());
+    }
+
+    #[test]
+    fn spin_cycles() {
+        let mut platform = parse(INPUT.trim()).expect("valid parse");
+        spin_cycle(&mut platform);
+        let expected = r"
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+        assert_eq!(expected.trim_start(), platform.to_string().trim_end());
+
+        spin_cycle(&mut platform);
+        spin_cycle(&mut platform);
+        let expected = r"
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+        assert_eq!(expected.trim_start(), platform.to_string().trim_end());
+    }
+}
+
+mod part_one {
+    use super::{north_load, tilt, Grid, Result, Rock};
+    use crate::Direction;
+
+    pub fn solve(platform: &Grid<Rock>) -> Result<usize> {
+        let mut platform = platform.clone();
+        tilt(&mut platform, Direction::North);
+        Ok(north_load(&platform))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let platform = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(136), solve(&platform));
+        }
+    }
+}
+
+mod part_two {
+    use super::{north_load, spin_cycles, Grid, Result, Rock};
+
+    const CYCLES: usize = 1_000_000_000;
+
+    pub fn solve(platform: &Grid<Rock>) -> Result<usize> {
+        Ok(north_load(&spin_cycles(platform, CYCLES)))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let platform = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(64), solve(&platform));
+        }
+    }
+}
+
+mod parser {
+    use super::{Grid, Result, Rock};
+
+    pub fn parse(input: &str) -> Result<Grid<Rock>> {
+        Grid::parse_block(input, input, |c| match c {
+            'O' => Some(Rock::Round),
+            '#' => Some(Rock::Cube),
+            '.' => Some(Rock::Empty),
+            _ => None,
+        })
+    }
 }
@@ -1,9 +1,223 @@
-use super::{PuzzleError, Result};
+use crate::{Answer, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Step>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let power = part_two::solve(input)?;
+        Ok(power.into())
+    }
+}
+
+const BOX_COUNT: usize = 256;
+
+/// The Holiday ASCII String Helper algorithm.
+pub fn hash(text: &str) -> usize {
+    text.bytes()
+        .fold(0, |value, byte| ((value + byte as usize) * 17) % BOX_COUNT)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Lens<'a> {
+    label: &'a str,
+    focal_length: u8,
+}
+
+/// The HASHMAP of boxes of lenses, each box keeping its lenses in order.
+struct Boxes<'a> {
+    boxes: Vec<Vec<Lens<'a>>>,
+}
+
+impl<'a> Boxes<'a> {
+    fn new() -> Boxes<'a> {
+        Boxes {
+            boxes: (0..BOX_COUNT).map(|_| vec![]).collect(),
+        }
+    }
+
+    fn apply(&mut self, step: &'a Step) {
+        let lenses = &mut self.boxes[hash(&step.label)];
+        let position = lenses.iter().position(|lens| lens.label == step.label);
+        match (&step.operation, position) {
+            (Operation::Remove, Some(position)) => {
+                lenses.remove(position);
+            }
+            (Operation::Remove, None) => {}
+            (Operation::Insert(focal_length), Some(position)) => {
+                lenses[position].focal_length = *focal_length;
+            }
+            (Operation::Insert(focal_length), None) => lenses.push(Lens {
+                label: &step.label,
+                focal_length: *focal_length,
+            }),
+        }
+    }
+
+    fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_index, lenses)| {
+                lenses.iter().enumerate().map(move |(slot, lens)| {
+                    (box_index + 1) * (slot + 1) * lens.focal_length as usize
+                })
+            })
+            .sum()
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+#[cfg(test)]
+mod tests {
+    use super::hash;
+
+    pub(super) const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn hash_test() {
+        assert_eq!(52, hash("HASH"));
+        assert_eq!(30, hash("rn=1"));
+        assert_eq!(0, hash("rn"));
+        assert_eq!(3, hash("pc"));
+    }
+}
+
+mod part_one {
+    use super::{hash, Result, Step};
+
+    pub fn solve(steps: &[Step]) -> Result<usize> {
+        Ok(steps.iter().map(|step| hash(&step.text)).sum())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let steps = parse(INPUT).expect("valid parse");
+            assert_eq!(Ok(1320), solve(&steps));
+        }
+    }
+}
+
+mod part_two {
+    use super::{Boxes, Result, Step};
+
+    pub fn solve(steps: &[Step]) -> Result<usize> {
+        let mut boxes = Boxes::new();
+        for step in steps {
+            boxes.apply(step);
+        }
+        Ok(boxes.focusing_power())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let steps = parse(INPUT).expect("valid parse");
+            assert_eq!(Ok(145), solve(&steps));
+        }
+    }
+}
+
+mod parser {
+    use super::{Operation, Result, Step};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Vec<Step>> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| step.trim_matches(['\n', '\r']))
+            .enumerate()
+            .map(|(index, step)| {
+                parse_step(step).ok_or_else(|| {
+                    PuzzleError::invalid_input(&format!("invalid step {} '{step}'", index + 1))
+                })
+            })
+            .collect()
+    }
+
+    fn parse_step(step: &str) -> Option<Step> {
+        let (label, operation) = if let Some(label) = step.strip_suffix('-') {
+            (label, Operation::Remove)
+        } else {
+            let (label, focal_length) = step.split_once('=')?;
+            let focal_length = focal_length.parse::<u8>().ok()?;
+            if !(1..=9).contains(&focal_length) {
+                return None;
+            }
+            (label, Operation::Insert(focal_length))
+        };
+        if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+            return None;
+        }
+        Some(Step {
+            text: step.to_owned(),
+            label: label.to_owned(),
+            operation,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{parse, Operation, Step};
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_input() {
+            assert_eq!(
+                Ok(vec![
+                    Step {
+                        text: "rn=1".to_owned(),
+                        label: "rn".to_owned(),
+                        operation: Operation::Insert(1),
+                    },
+                    Step {
+                        text: "cm-".to_owned(),
+                        label: "cm".to_owned(),
+                        operation: Operation::Remove,
+                    },
+                ]),
+                parse("rn=1,cm-")
+            );
+        }
+
+        #[test]
+        fn parse_invalid_step() {
+            assert_eq!(
+                Err(PuzzleError::invalid_input("invalid step 2 'cm=x'")),
+                parse("rn=1,cm=x")
+            );
+            assert!(parse("rn=1,=3").is_err());
+            assert!(parse("rn=0").is_err());
+        }
+    }
 }
@@ -1,9 +1,261 @@
-use super::{PuzzleError, Result};
+use std::{collections::HashSet, fmt::Display};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Direction, Grid, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Contraption;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let count = part_one::solve(input)?;
+        Ok(count.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let count = part_two::solve(input)?;
+        Ok(count.into())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    /// A `/` mirror.
+    ForwardMirror,
+    /// A `\` mirror.
+    BackMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+impl Tile {
+    /// The directions a beam leaves the tile in when entering it heading in
+    /// `direction`.
+    fn deflect(&self, direction: Direction) -> Vec<Direction> {
+        use Direction::*;
+
+        match (self, direction) {
+            (Tile::ForwardMirror, North) => vec![East],
+            (Tile::ForwardMirror, East) => vec![North],
+            (Tile::ForwardMirror, South) => vec![West],
+            (Tile::ForwardMirror, West) => vec![South],
+            (Tile::BackMirror, North) => vec![West],
+            (Tile::BackMirror, West) => vec![North],
+            (Tile::BackMirror, South) => vec![East],
+            (Tile::BackMirror, East) => vec![South],
+            (Tile::VerticalSplitter, East | West) => vec![North, South],
+            (Tile::HorizontalSplitter, North | South) => vec![East, West],
+            (_, direction) => vec![direction],
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Tile::Empty => '.',
+            Tile::ForwardMirror => '/',
+            Tile::BackMirror => '\\',
+            Tile::VerticalSplitter => '|',
+            Tile::HorizontalSplitter => '-',
+        };
+        write!(f, "{c}")
+    }
+}
+
+pub struct Contraption {
+    tiles: Grid<Tile>,
+}
+
+impl Contraption {
+    fn new(tiles: Grid<Tile>) -> Contraption {
+        Contraption { tiles }
+    }
+
+    /// Follows the beam entering the tile at `start` heading in `direction`
+    /// and all the beams it splits into.
+    pub fn energize(&self, start: (usize, usize), direction: Direction) -> Energized<'_> {
+        // the directions each tile has been entered in, as bits
+        let mut visited = Grid::filled(self.tiles.width(), self.tiles.height(), 0u8);
+        let mut beams = vec![(start, direction)];
+        while let Some((position, direction)) = beams.pop() {
+            let bit = 1 << direction as u8;
+            if visited[position] & bit != 0 {
+                continue;
+            }
+            visited[position] |= bit;
+            for direction in self.tiles[position].deflect(direction) {
+                if let Some(next) = self.tiles.step(position, direction) {
+                    beams.push((next, direction));
+                }
+            }
+        }
+
+        let tiles = visited.find(|&directions| directions != 0).collect();
+        Energized {
+            contraption: self,
+            tiles,
+        }
+    }
+
+    /// Every beam entering the contraption from one of its edges.
+    fn edge_entries(&self) -> Vec<((usize, usize), Direction)> {
+        let (last_row, last_column) = (self.tiles.height() - 1, self.tiles.width() - 1);
+        let rows = (0..=last_row).flat_map(|row| {
+            [
+                ((row, 0), Direction::East),
+                ((row, last_column), Direction::West),
+            ]
+        });
+        let columns = (0..=last_column).flat_map(|column| {
+            [
+                ((0, column), Direction::South),
+                ((last_row, column), Direction::North),
+            ]
+        });
+        rows.chain(columns).collect()
+    }
+}
+
+impl Display for Contraption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.tiles.fmt(f)
+    }
+}
+
+/// The tiles a beam passed through, rendered with `#` for energized tiles.
+pub struct Energized<'a> {
+    contraption: &'a Contraption,
+    tiles: HashSet<(usize, usize)>,
+}
+
+impl Energized<'_> {
+    pub fn tiles(&self) -> &HashSet<(usize, usize)> {
+        &self.tiles
+    }
+
+    pub fn count(&self) -> usize {
+        self.tiles.len()
+    }
+}
+
+impl Display for Energized<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tiles = &self.contraption.tiles;
+        for row in 0..tiles.height() {
+            for column in 0..tiles.width() {
+                let c = if self.tiles.contains(&(row, column)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parser::parse;
+    use crate::Direction;
+
+    pub(super) const INPUT: &str = r"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn energized_render() {
+        let contraption = parse(INPUT.trim()).expect("valid parse");
+        let energized = contraption.energize((0, 0), Direction::East);
+        let expected = r"
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..";
+        assert_eq!(expected.trim_start(), energized.to_string().trim_end());
+    }
+}
+
+mod part_one {
+    use super::{Contraption, Result};
+    use crate::Direction;
+
+    pub fn solve(contraption: &Contraption) -> Result<usize> {
+        Ok(contraption.energize((0, 0), Direction::East).count())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let contraption = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(46), solve(&contraption));
+        }
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+mod part_two {
+    use rayon::prelude::*;
+
+    use super::{Contraption, Result};
+
+    pub fn solve(contraption: &Contraption) -> Result<usize> {
+        Ok(contraption
+            .edge_entries()
+            .into_par_iter()
+            .map(|(start, direction)| contraption.energize(start, direction).count())
+            .max()
+            .unwrap_or_default())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let contraption = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(51), solve(&contraption));
+        }
+    }
+}
+
+mod parser {
+    use super::{Contraption, Grid, Result, Tile};
+
+    pub fn parse(input: &str) -> Result<Contraption> {
+        let tiles = Grid::parse_block(input, input, |c| match c {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::ForwardMirror),
+            '\\' => Some(Tile::BackMirror),
+            '|' => Some(Tile::VerticalSplitter),
+            '-' => Some(Tile::HorizontalSplitter),
+            _ => None,
+        })?;
+        Ok(Contraption::new(tiles))
+    }
 }
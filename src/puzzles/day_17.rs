@@ -1,9 +1,260 @@
-use super::{PuzzleError, Result};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Direction, Grid, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let heat_loss = part_one::solve(input)?;
+        Ok(heat_loss.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let heat_loss = part_two::solve(input)?;
+        Ok(heat_loss.into())
+    }
+}
+
+/// How many blocks a crucible must and may move before turning.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Crucible {
+    min_run: usize,
+    max_run: usize,
+}
+
+pub const CRUCIBLE: Crucible = Crucible {
+    min_run: 1,
+    max_run: 3,
+};
+
+pub const ULTRA_CRUCIBLE: Crucible = Crucible {
+    min_run: 4,
+    max_run: 10,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct State {
+    position: (usize, usize),
+    direction: Direction,
+    run: usize,
+}
+
+/// The blocks a crucible moves through from the top left, excluding its
+/// starting block, and the heat lost on the way.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: u32,
+    pub path: Vec<((usize, usize), Direction)>,
+}
+
+impl Route {
+    /// The map with the route's blocks marked by the direction moved into them.
+    pub fn render(&self, map: &Grid<u8>) -> String {
+        let moves = self.path.iter().copied().collect::<HashMap<_, _>>();
+        let mut rendered = String::new();
+        for row in 0..map.height() {
+            for column in 0..map.width() {
+                rendered.push(match moves.get(&(row, column)) {
+                    Some(Direction::North) => '^',
+                    Some(Direction::East) => '>',
+                    Some(Direction::South) => 'v',
+                    Some(Direction::West) => '<',
+                    None => char::from(b'0' + map[(row, column)]),
+                });
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+impl Crucible {
+    /// Dijkstra's search over (position, direction, run length) states for the
+    /// route to the bottom right with the least heat loss.
+    pub fn least_heat_loss(&self, map: &Grid<u8>) -> Option<Route> {
+        let target = (map.height() - 1, map.width() - 1);
+        let mut heat_losses = HashMap::new();
+        let mut previous = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        for direction in [Direction::East, Direction::South] {
+            let state = State {
+                position: (0, 0),
+                direction,
+                run: 0,
+            };
+            heat_losses.insert(state, 0);
+            queue.push(Reverse((0, state)));
+        }
+
+        while let Some(Reverse((heat_loss, state))) = queue.pop() {
+            if state.position == target && state.run >= self.min_run {
+                return Some(Route {
+                    heat_loss,
+                    path: Crucible::path(&previous, state),
+                });
+            }
+            if heat_losses
+                .get(&state)
+                .is_some_and(|&best| heat_loss > best)
+            {
+                continue;
+            }
+            for next in self.moves(map, &state) {
+                let next_heat_loss = heat_loss + map[next.position] as u32;
+                if heat_losses
+                    .get(&next)
+                    .is_none_or(|&best| next_heat_loss < best)
+                {
+                    heat_losses.insert(next, next_heat_loss);
+                    previous.insert(next, state);
+                    queue.push(Reverse((next_heat_loss, next)));
+                }
+            }
+        }
+        None
+    }
+
+    fn moves(&self, map: &Grid<u8>, state: &State) -> Vec<State> {
+        let mut moves = vec![];
+        if state.run < self.max_run {
+            moves.push((state.direction, state.run + 1));
+        }
+        if state.run >= self.min_run {
+            moves.push((state.direction.turn_left(), 1));
+            moves.push((state.direction.turn_right(), 1));
+        }
+        moves
+            .into_iter()
+            .filter_map(|(direction, run)| {
+                map.step(state.position, direction).map(|position| State {
+                    position,
+                    direction,
+                    run,
+                })
+            })
+            .collect()
+    }
+
+    fn path(previous: &HashMap<State, State>, end: State) -> Vec<((usize, usize), Direction)> {
+        let mut path = vec![];
+        let mut state = end;
+        while let Some(&prior) = previous.get(&state) {
+            path.push((state.position, state.direction));
+            state = prior;
+        }
+        path.reverse();
+        path
+    }
+}
+
+fn solve(map: &Grid<u8>, crucible: &Crucible) -> Result<u32> {
+    crucible
+        .least_heat_loss(map)
+        .map(|route| route.heat_loss)
+        .ok_or_else(|| PuzzleError::invalid_input("no route to the machine parts factory"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, CRUCIBLE};
+
+    pub(super) const INPUT: &str = r"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[test]
+    fn route_render() {
+        let map = parse(INPUT.trim()).expect("valid parse");
+        let route = CRUCIBLE.least_heat_loss(&map).expect("route");
+        let rendered = route.render(&map);
+        assert_eq!(102, route.heat_loss);
+        assert_eq!(
+            route.path.len(),
+            rendered.chars().filter(|c| "^>v<".contains(*c)).count()
+        );
+        assert!(rendered.starts_with("2>>"));
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+mod part_one {
+    use super::{solve as least_heat_loss, Grid, Result, CRUCIBLE};
+
+    pub fn solve(map: &Grid<u8>) -> Result<u32> {
+        least_heat_loss(map, &CRUCIBLE)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let map = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(102), solve(&map));
+        }
+    }
+}
+
+mod part_two {
+    use super::{solve as least_heat_loss, Grid, Result, ULTRA_CRUCIBLE};
+
+    pub fn solve(map: &Grid<u8>) -> Result<u32> {
+        least_heat_loss(map, &ULTRA_CRUCIBLE)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let map = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(94), solve(&map));
+        }
+
+        #[test]
+        fn part_two_unfortunate() {
+            let input = r"
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+            let map = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(71), solve(&map));
+        }
+    }
+}
+
+mod parser {
+    use super::{Grid, Result};
+
+    pub fn parse(input: &str) -> Result<Grid<u8>> {
+        Grid::parse_block(input, input, |c| {
+            c.to_digit(10)
+                .filter(|&digit| digit > 0)
+                .map(|digit| digit as u8)
+        })
+    }
 }
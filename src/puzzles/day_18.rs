@@ -1,9 +1,234 @@
-use super::{PuzzleError, Result};
+use crate::{polygon, Answer, Direction, Result, Solution};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<DigStep>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let volume = part_one::solve(input)?;
+        Ok(volume.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let volume = part_two::solve(input)?;
+        Ok(volume.into())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DigStep {
+    direction: Direction,
+    distance: i64,
+    color: u32,
+}
+
+impl DigStep {
+    /// The step actually encoded in the color: five hex digits of distance
+    /// followed by a direction digit.
+    fn decoded(&self) -> DigStep {
+        let direction = color_direction(self.color)
+            .expect("the parser only accepts colors with a direction digit");
+        DigStep {
+            direction,
+            distance: (self.color >> 4) as i64,
+            color: self.color,
+        }
+    }
+}
+
+/// The direction of a color's last hex digit, if it is one of 0 to 3.
+fn color_direction(color: u32) -> Option<Direction> {
+    match color & 0xf {
+        0 => Some(Direction::East),
+        1 => Some(Direction::South),
+        2 => Some(Direction::West),
+        3 => Some(Direction::North),
+        _ => None,
+    }
+}
+
+/// The cubic meters of lava the lagoon dug by `steps` holds, counting the
+/// trench itself.
+pub fn lagoon_volume<'a>(steps: impl IntoIterator<Item = &'a DigStep>) -> i64 {
+    let mut position = (0, 0);
+    let mut vertices = vec![];
+    for step in steps {
+        let (row, column) = position;
+        position = match step.direction {
+            Direction::North => (row - step.distance, column),
+            Direction::East => (row, column + step.distance),
+            Direction::South => (row + step.distance, column),
+            Direction::West => (row, column - step.distance),
+        };
+        vertices.push(position);
+    }
+    polygon::lattice_points(&vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, DigStep, Direction};
+
+    pub(super) const INPUT: &str = r"
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn decoded() {
+        let steps = parse(INPUT.trim()).expect("valid parse");
+        assert_eq!(
+            DigStep {
+                direction: Direction::East,
+                distance: 461937,
+                color: 0x70c710,
+            },
+            steps[0].decoded()
+        );
+        assert_eq!(Direction::North, steps[7].decoded().direction);
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+mod part_one {
+    use super::{lagoon_volume, DigStep, Result};
+
+    pub fn solve(steps: &[DigStep]) -> Result<i64> {
+        Ok(lagoon_volume(steps))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let steps = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(62), solve(&steps));
+        }
+    }
+}
+
+mod part_two {
+    use super::{lagoon_volume, DigStep, Result};
+
+    pub fn solve(steps: &[DigStep]) -> Result<i64> {
+        let steps = steps.iter().map(DigStep::decoded).collect::<Vec<_>>();
+        Ok(lagoon_volume(&steps))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let steps = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(952408144115), solve(&steps));
+        }
+    }
+}
+
+mod parser {
+    use super::{color_direction, DigStep, Direction, Result};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Vec<DigStep>> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_step(input, line))
+            .collect()
+    }
+
+    fn parse_step(input: &str, line: &str) -> Result<DigStep> {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [direction, distance, color] = fields[..] else {
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                line,
+                "expected a direction, distance and color",
+            ));
+        };
+        let direction = match direction {
+            "U" => Direction::North,
+            "R" => Direction::East,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            _ => {
+                return Err(PuzzleError::invalid_input_at(
+                    input,
+                    direction,
+                    "invalid direction",
+                ))
+            }
+        };
+        let distance = distance
+            .parse::<i64>()
+            .ok()
+            .filter(|&distance| distance > 0)
+            .ok_or_else(|| PuzzleError::invalid_input_at(input, distance, "invalid distance"))?;
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| PuzzleError::invalid_input_at(input, color, "invalid color"))?;
+        let color = u32::from_str_radix(hex, 16)
+            .map_err(|_| PuzzleError::invalid_input_at(input, hex, "invalid color"))?;
+        if color_direction(color).is_none() {
+            return Err(PuzzleError::invalid_input_at(
+                input,
+                &hex[5..],
+                "color direction digit must be 0 to 3",
+            ));
+        }
+        Ok(DigStep {
+            direction,
+            distance,
+            color,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse;
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_invalid_step() {
+            assert!(matches!(
+                parse("R 6 (#70c710)\nX 5 (#0dc571)"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+            assert!(matches!(
+                parse("R 6 (#70c7)"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+        }
+
+        #[test]
+        fn parse_invalid_color_direction() {
+            let err = parse("R 6 (#70c710)\nD 5 (#0dc574)").expect_err("direction digit 4");
+            assert_eq!(
+                "invalid input at line 2, column 12: color direction digit must be 0 to 3\n  |\n2 | D 5 (#0dc574)\n  |            ^",
+                err.to_string()
+            );
+        }
+    }
 }
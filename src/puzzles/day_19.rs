@@ -1,9 +1,469 @@
-use super::{PuzzleError, Result};
+use std::{collections::HashMap, ops::Range};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = System;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_one::solve(input)?;
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let combinations = part_two::solve(input)?;
+        Ok(combinations.into())
+    }
+}
+
+const START_WORKFLOW: &str = "in";
+
+const RATINGS: Range<u64> = 1..4001;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Category {
+    ExtremelyCoolLooking,
+    Musical,
+    Aerodynamic,
+    Shiny,
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Condition {
+    category: Category,
+    comparison: Comparison,
+    value: u64,
+}
+
+impl Condition {
+    fn matches(&self, part: &Part) -> bool {
+        let rating = part.ratings[self.category as usize];
+        match self.comparison {
+            Comparison::LessThan => rating < self.value,
+            Comparison::GreaterThan => rating > self.value,
+        }
+    }
+
+    /// The ratings in `range` matching the condition and the rest.
+    fn split(&self, range: &Range<u64>) -> (Range<u64>, Range<u64>) {
+        match self.comparison {
+            Comparison::LessThan => {
+                let split = self.value.clamp(range.start, range.end);
+                (range.start..split, split..range.end)
+            }
+            Comparison::GreaterThan => {
+                let split = self.value.saturating_add(1).clamp(range.start, range.end);
+                (split..range.end, range.start..split)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    condition: Option<Condition>,
+    target: Target,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Part {
+    ratings: [u64; 4],
+}
+
+impl Part {
+    fn rating_sum(&self) -> u64 {
+        self.ratings.iter().sum()
+    }
+}
+
+/// The ratings of every part in a 4-dimensional box, one range per category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PartRange {
+    ratings: [Range<u64>; 4],
+}
+
+impl PartRange {
+    fn empty() -> PartRange {
+        PartRange {
+            ratings: [0..0, 0..0, 0..0, 0..0],
+        }
+    }
+
+    fn combinations(&self) -> u64 {
+        self.ratings
+            .iter()
+            .map(|range| range.end.saturating_sub(range.start))
+            .product()
+    }
+
+    /// The parts in the range matching `condition` and the rest, either of
+    /// which may be empty.
+    fn split(&self, condition: &Condition) -> (PartRange, PartRange) {
+        let category = condition.category as usize;
+        let (matching, rest) = condition.split(&self.ratings[category]);
+        let mut matching_range = self.clone();
+        matching_range.ratings[category] = matching;
+        let mut rest_range = self.clone();
+        rest_range.ratings[category] = rest;
+        (matching_range, rest_range)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct System {
+    workflows: HashMap<String, Vec<Rule>>,
+    parts: Vec<Part>,
+}
+
+impl System {
+    fn no_fallback_rule(name: &str) -> PuzzleError {
+        PuzzleError::invalid_input(&format!("workflow '{name}' has no fallback rule"))
+    }
+
+    fn rules(&self, name: &str) -> Result<&[Rule]> {
+        self.workflows
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| PuzzleError::invalid_input(&format!("unknown workflow '{name}'")))
+    }
+
+    fn accepts(&self, part: &Part) -> Result<bool> {
+        let mut name = START_WORKFLOW;
+        // a workflow visited twice would send the part around forever
+        for _ in 0..=self.workflows.len() {
+            let rule = self
+                .rules(name)?
+                .iter()
+                .find(|rule| rule.condition.is_none_or(|c| c.matches(part)))
+                .ok_or_else(|| System::no_fallback_rule(name))?;
+            match &rule.target {
+                Target::Accept => return Ok(true),
+                Target::Reject => return Ok(false),
+                Target::Workflow(next) => name = next,
+            }
+        }
+        Err(PuzzleError::invalid_input("workflows form a cycle"))
+    }
+
+    /// The number of distinct rating combinations the workflows accept,
+    /// splitting rating ranges at each rule rather than enumerating parts.
+    fn accepted_combinations(&self) -> Result<u64> {
+        let start = PartRange {
+            ratings: [RATINGS, RATINGS, RATINGS, RATINGS],
+        };
+        let mut pending = vec![(START_WORKFLOW, start, 0)];
+        let mut accepted = 0;
+        while let Some((name, mut range, depth)) = pending.pop() {
+            if depth > self.workflows.len() {
+                return Err(PuzzleError::invalid_input("workflows form a cycle"));
+            }
+            for rule in self.rules(name)? {
+                let matching = match rule.condition {
+                    Some(condition) => {
+                        let (matching, rest) = range.split(&condition);
+                        range = rest;
+                        matching
+                    }
+                    None => std::mem::replace(&mut range, PartRange::empty()),
+                };
+                if matching.combinations() == 0 {
+                    continue;
+                }
+                match &rule.target {
+                    Target::Accept => accepted += matching.combinations(),
+                    Target::Reject => {}
+                    Target::Workflow(next) => pending.push((next, matching, depth + 1)),
+                }
+            }
+            if range.combinations() > 0 {
+                return Err(System::no_fallback_rule(name));
+            }
+        }
+        Ok(accepted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, Category, Comparison, Condition, PartRange, RATINGS};
+    use crate::PuzzleError;
+
+    pub(super) const INPUT: &str = r"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn split_range() {
+        let range = PartRange {
+            ratings: [RATINGS, RATINGS, RATINGS, RATINGS],
+        };
+        let condition = Condition {
+            category: Category::Aerodynamic,
+            comparison: Comparison::GreaterThan,
+            value: 1716,
+        };
+        let (matching, rest) = range.split(&condition);
+        assert_eq!(1717..4001, matching.ratings[2]);
+        assert_eq!(1..1717, rest.ratings[2]);
+        assert_eq!(
+            range.combinations(),
+            matching.combinations() + rest.combinations()
+        );
+
+        let condition = Condition {
+            comparison: Comparison::GreaterThan,
+            value: u64::MAX,
+            ..condition
+        };
+        let (matching, rest) = range.split(&condition);
+        assert_eq!(0, matching.combinations());
+        assert_eq!(range, rest);
+    }
+
+    #[test]
+    fn missing_fallback_rule() {
+        let system = parse("in{x<2000:A}\n\n{x=2500,m=1,a=1,s=1}").expect("valid parse");
+        let err = Some(PuzzleError::invalid_input(
+            "workflow 'in' has no fallback rule",
+        ));
+        assert_eq!(err, system.accepts(&system.parts[0]).err());
+        assert_eq!(err, system.accepted_combinations().err());
+    }
+}
+
+mod part_one {
+    use super::{Result, System};
+
+    pub fn solve(system: &System) -> Result<u64> {
+        let mut sum = 0;
+        for part in &system.parts {
+            if system.accepts(part)? {
+                sum += part.rating_sum();
+            }
+        }
+        Ok(sum)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let system = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(19114), solve(&system));
+        }
+    }
+}
+
+mod part_two {
+    use super::{Result, System};
+
+    pub fn solve(system: &System) -> Result<u64> {
+        system.accepted_combinations()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let system = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(167409079868000), solve(&system));
+        }
+    }
+}
+
+mod parser {
+    use std::collections::HashMap;
+
+    use super::{Category, Comparison, Condition, Part, Result, Rule, System, Target};
+    use crate::{parser::blocks, PuzzleError};
+
+    pub fn parse(input: &str) -> Result<System> {
+        let [workflow_block, part_block] = blocks(input)[..] else {
+            return Err(PuzzleError::invalid_input(
+                "expected a block of workflows and a block of parts",
+            ));
+        };
+        let mut workflows = HashMap::new();
+        for line in workflow_block
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let (name, rules) = parse_workflow(input, line)?;
+            if workflows.insert(name.to_owned(), rules).is_some() {
+                return Err(PuzzleError::invalid_input_at(
+                    input,
+                    name,
+                    "duplicate workflow",
+                ));
+            }
+        }
+        let parts = part_block
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                parse_part(line)
+                    .ok_or_else(|| PuzzleError::invalid_input_at(input, line, "invalid part"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(System { workflows, parts })
+    }
+
+    fn parse_workflow<'a>(input: &str, line: &'a str) -> Result<(&'a str, Vec<Rule>)> {
+        let (name, rules) = line
+            .strip_suffix('}')
+            .and_then(|line| line.split_once('{'))
+            .filter(|(name, _)| !name.is_empty())
+            .ok_or_else(|| PuzzleError::invalid_input_at(input, line, "invalid workflow"))?;
+        let rules = rules
+            .split(',')
+            .map(|rule| {
+                parse_rule(rule)
+                    .ok_or_else(|| PuzzleError::invalid_input_at(input, rule, "invalid rule"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((name, rules))
+    }
+
+    fn parse_rule(rule: &str) -> Option<Rule> {
+        let (condition, target) = match rule.split_once(':') {
+            Some((condition, target)) => (Some(parse_condition(condition)?), target),
+            None => (None, rule),
+        };
+        let target = match target {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            "" => return None,
+            name => Target::Workflow(name.to_owned()),
+        };
+        Some(Rule { condition, target })
+    }
+
+    fn parse_condition(condition: &str) -> Option<Condition> {
+        let mut chars = condition.chars();
+        let category = parse_category(chars.next()?)?;
+        let comparison = match chars.next()? {
+            '<' => Comparison::LessThan,
+            '>' => Comparison::GreaterThan,
+            _ => return None,
+        };
+        let value = chars.as_str().parse().ok()?;
+        Some(Condition {
+            category,
+            comparison,
+            value,
+        })
+    }
+
+    fn parse_category(c: char) -> Option<Category> {
+        match c {
+            'x' => Some(Category::ExtremelyCoolLooking),
+            'm' => Some(Category::Musical),
+            'a' => Some(Category::Aerodynamic),
+            's' => Some(Category::Shiny),
+            _ => None,
+        }
+    }
+
+    fn parse_part(line: &str) -> Option<Part> {
+        let ratings = line.strip_prefix('{')?.strip_suffix('}')?;
+        let mut part = Part { ratings: [0; 4] };
+        let mut seen = [false; 4];
+        for rating in ratings.split(',') {
+            let (category, value) = rating.split_once('=')?;
+            let mut chars = category.chars();
+            let category = parse_category(chars.next()?)? as usize;
+            if chars.next().is_some() || seen[category] {
+                return None;
+            }
+            seen[category] = true;
+            part.ratings[category] = value.parse().ok()?;
+        }
+        seen.iter().all(|&seen| seen).then_some(part)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{parse, parse_rule, Category, Comparison, Condition, Rule, Target};
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_rules() {
+            assert_eq!(
+                Some(Rule {
+                    condition: Some(Condition {
+                        category: Category::Musical,
+                        comparison: Comparison::GreaterThan,
+                        value: 2090,
+                    }),
+                    target: Target::Accept,
+                }),
+                parse_rule("m>2090:A")
+            );
+            assert_eq!(
+                Some(Rule {
+                    condition: None,
+                    target: Target::Workflow("rfg".to_owned()),
+                }),
+                parse_rule("rfg")
+            );
+            assert_eq!(None, parse_rule("q>1:A"));
+        }
+
+        #[test]
+        fn parse_duplicate_workflow() {
+            let err = parse("in{A}\nin{R}\n\n{x=1,m=2,a=3,s=4}").expect_err("duplicate workflow");
+            assert_eq!(
+                "invalid input at line 2, column 1: duplicate workflow\n  |\n2 | in{R}\n  | ^^",
+                err.to_string()
+            );
+        }
+
+        #[test]
+        fn parse_invalid_part() {
+            assert!(matches!(
+                parse("in{A}\n\n{x=1,m=2,a=3}"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+        }
+    }
 }
@@ -1,9 +1,392 @@
-use super::{PuzzleError, Result};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let product = part_one::solve(input)?;
+        Ok(product.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let presses = part_two::solve(input)?;
+        Ok(presses.into())
+    }
+}
+
+const BROADCASTER: &str = "broadcaster";
+
+const BUTTON: &str = "button";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    kind: ModuleKind,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Signal<'a> {
+    from: &'a str,
+    to: &'a str,
+    pulse: Pulse,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Network {
+    modules: HashMap<String, Module>,
+}
+
+impl Network {
+    /// The names of the modules sending pulses to `name`.
+    fn inputs(&self, name: &str) -> Vec<&str> {
+        let mut inputs = self
+            .modules
+            .iter()
+            .filter(|(_, module)| module.outputs.iter().any(|output| output == name))
+            .map(|(input, _)| input.as_str())
+            .collect::<Vec<_>>();
+        inputs.sort();
+        inputs
+    }
+
+    /// The module graph in Graphviz DOT format, with flip-flops as boxes and
+    /// conjunctions as diamonds.
+    pub fn graphviz(&self) -> String {
+        let mut names = self.modules.keys().collect::<Vec<_>>();
+        names.sort();
+        let mut dot = String::from("digraph modules {\n");
+        for name in &names {
+            let shape = match self.modules[*name].kind {
+                ModuleKind::Broadcaster => "doublecircle",
+                ModuleKind::FlipFlop => "box",
+                ModuleKind::Conjunction => "diamond",
+            };
+            writeln!(dot, "    {name} [shape={shape}];").expect("write to string");
+        }
+        for name in &names {
+            for output in &self.modules[*name].outputs {
+                writeln!(dot, "    {name} -> {output};").expect("write to string");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The state of every flip-flop and conjunction memory as the button is
+/// pressed.
+struct Simulation<'a> {
+    network: &'a Network,
+    flip_flops: HashMap<&'a str, bool>,
+    memories: HashMap<&'a str, HashMap<&'a str, Pulse>>,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(network: &'a Network) -> Simulation<'a> {
+        let mut flip_flops = HashMap::new();
+        let mut memories = HashMap::new();
+        for (name, module) in &network.modules {
+            match module.kind {
+                ModuleKind::FlipFlop => {
+                    flip_flops.insert(name.as_str(), false);
+                }
+                ModuleKind::Conjunction => {
+                    let memory = network
+                        .inputs(name)
+                        .into_iter()
+                        .map(|input| (input, Pulse::Low))
+                        .collect();
+                    memories.insert(name.as_str(), memory);
+                }
+                ModuleKind::Broadcaster => {}
+            }
+        }
+        Simulation {
+            network,
+            flip_flops,
+            memories,
+        }
+    }
+
+    /// Pushes the button, passing each pulse sent to `observe` in the order
+    /// they are processed.
+    fn press<F>(&mut self, mut observe: F)
+    where
+        F: FnMut(&Signal<'a>),
+    {
+        let mut queue = VecDeque::from([Signal {
+            from: BUTTON,
+            to: BROADCASTER,
+            pulse: Pulse::Low,
+        }]);
+        while let Some(signal) = queue.pop_front() {
+            observe(&signal);
+            let Some((name, module)) = self.network.modules.get_key_value(signal.to) else {
+                continue;
+            };
+            let pulse = match module.kind {
+                ModuleKind::Broadcaster => signal.pulse,
+                ModuleKind::FlipFlop => {
+                    if signal.pulse == Pulse::High {
+                        continue;
+                    }
+                    let on = self.flip_flops.entry(name).or_default();
+                    *on = !*on;
+                    if *on {
+                        Pulse::High
+                    } else {
+                        Pulse::Low
+                    }
+                }
+                ModuleKind::Conjunction => {
+                    let memory = self.memories.entry(name).or_default();
+                    memory.insert(signal.from, signal.pulse);
+                    if memory.values().all(|&pulse| pulse == Pulse::High) {
+                        Pulse::Low
+                    } else {
+                        Pulse::High
+                    }
+                }
+            };
+            for output in &module.outputs {
+                queue.push_back(Signal {
+                    from: name,
+                    to: output,
+                    pulse,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parser::parse;
+
+    pub(super) const INPUT: &str = r"
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    pub(super) const INTERESTING_INPUT: &str = r"
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn graphviz() {
+        let network = parse(INTERESTING_INPUT.trim()).expect("valid parse");
+        let expected = r"
+digraph modules {
+    a [shape=box];
+    b [shape=box];
+    broadcaster [shape=doublecircle];
+    con [shape=diamond];
+    inv [shape=diamond];
+    a -> inv;
+    a -> con;
+    b -> con;
+    broadcaster -> a;
+    con -> output;
+    inv -> b;
+}";
+        assert_eq!(expected.trim_start(), network.graphviz().trim_end());
+    }
+}
+
+mod part_one {
+    use super::{Network, Pulse, Result, Simulation};
+
+    const PRESSES: usize = 1000;
+
+    pub fn solve(network: &Network) -> Result<usize> {
+        let mut simulation = Simulation::new(network);
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..PRESSES {
+            simulation.press(|signal| match signal.pulse {
+                Pulse::Low => low += 1,
+                Pulse::High => high += 1,
+            });
+        }
+        Ok(low * high)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{
+            super::parser::parse,
+            super::tests::{INPUT, INTERESTING_INPUT},
+            solve,
+        };
+
+        #[test]
+        fn part_one() {
+            let network = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(32000000), solve(&network));
+        }
+
+        #[test]
+        fn part_one_interesting() {
+            let network = parse(INTERESTING_INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(11687500), solve(&network));
+        }
+    }
+}
+
+mod part_two {
+    use std::collections::HashMap;
+
+    use super::{ModuleKind, Network, Pulse, PuzzleError, Result, Simulation};
+
+    const MACHINE: &str = "rx";
+
+    const MAX_PRESSES: usize = 1 << 20;
+
+    /// The presses until the machine gets a low pulse. The machine is fed by
+    /// a single conjunction, which sends the low pulse once all its inputs
+    /// sent high pulses in the same press; each input does so on a fixed
+    /// cycle, so the answer is the least common multiple of the cycles.
+    pub fn solve(network: &Network) -> Result<usize> {
+        let [feeder] = network.inputs(MACHINE)[..] else {
+            return Err(PuzzleError::invalid_input(
+                "expected a single module sending to rx",
+            ));
+        };
+        if network.modules[feeder].kind != ModuleKind::Conjunction {
+            return Err(PuzzleError::invalid_input(
+                "expected a conjunction sending to rx",
+            ));
+        }
+        let inputs = network.inputs(feeder);
+        let mut cycles = HashMap::new();
+        let mut simulation = Simulation::new(network);
+        let mut presses = 0;
+        while cycles.len() < inputs.len() {
+            if presses == MAX_PRESSES {
+                return Err(PuzzleError::invalid_input(
+                    "rx feeder inputs never send high pulses",
+                ));
+            }
+            presses += 1;
+            simulation.press(|signal| {
+                if signal.to == feeder && signal.pulse == Pulse::High {
+                    cycles.entry(signal.from).or_insert(presses);
+                }
+            });
+        }
+        Ok(cycles.into_values().fold(1, num::integer::lcm))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, solve};
+        use crate::PuzzleError;
+
+        #[test]
+        fn part_two() {
+            // flip-flop counters inverted by conjunctions send high pulses
+            // to the hub every 4 and 8 presses
+            let input = r"
+broadcaster -> a1, b1
+%a1 -> a2
+%a2 -> ca
+&ca -> hub
+%b1 -> b2
+%b2 -> b3
+%b3 -> cb
+&cb -> hub
+&hub -> rx";
+            let network = parse(input.trim()).expect("valid parse");
+            assert_eq!(Ok(8), solve(&network));
+        }
+
+        #[test]
+        fn part_two_no_machine() {
+            let network = parse(super::super::tests::INPUT.trim()).expect("valid parse");
+            assert!(matches!(
+                solve(&network),
+                Err(PuzzleError::InvalidInput { .. })
+            ));
+        }
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+mod parser {
+    use std::collections::HashMap;
+
+    use super::{Module, ModuleKind, Network, Result, BROADCASTER};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Network> {
+        let mut modules = HashMap::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (name, outputs) = line.split_once(" -> ").ok_or_else(|| {
+                PuzzleError::invalid_input_at(input, line, "expected 'module -> outputs'")
+            })?;
+            let (kind, name) = if let Some(name) = name.strip_prefix('%') {
+                (ModuleKind::FlipFlop, name)
+            } else if let Some(name) = name.strip_prefix('&') {
+                (ModuleKind::Conjunction, name)
+            } else if name == BROADCASTER {
+                (ModuleKind::Broadcaster, name)
+            } else {
+                return Err(PuzzleError::invalid_input_at(
+                    input,
+                    name,
+                    "unknown module type",
+                ));
+            };
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(PuzzleError::invalid_input_at(
+                    input,
+                    line,
+                    "invalid module name",
+                ));
+            }
+            let outputs = outputs.split(',').map(|output| output.trim().to_owned());
+            let module = Module {
+                kind,
+                outputs: outputs.collect(),
+            };
+            if modules.insert(name.to_owned(), module).is_some() {
+                return Err(PuzzleError::invalid_input_at(
+                    input,
+                    name,
+                    "duplicate module",
+                ));
+            }
+        }
+        if !modules.contains_key(BROADCASTER) {
+            return Err(PuzzleError::invalid_input("missing broadcaster"));
+        }
+        Ok(Network { modules })
+    }
 }
@@ -1,9 +1,226 @@
-use super::{PuzzleError, Result};
+use std::collections::{HashMap, VecDeque};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{day_09::OASISReport, Answer, Direction, Grid, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Garden;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let plots = part_one::solve(input, part_one::STEPS)?;
+        Ok(plots.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let plots = part_two::solve(input, part_two::STEPS)?;
+        Ok(plots.into())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Plot,
+    Rock,
+    Start,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Garden {
+    tiles: Grid<Tile>,
+    start: (usize, usize),
+}
+
+impl Garden {
+    fn is_plot(&self, (row, column): (i64, i64)) -> bool {
+        let row = row.rem_euclid(self.tiles.height() as i64) as usize;
+        let column = column.rem_euclid(self.tiles.width() as i64) as usize;
+        self.tiles[(row, column)] != Tile::Rock
+    }
+
+    /// The fewest steps to every plot within `max_steps` of the start, with the
+    /// map repeating infinitely in every direction.
+    fn distances(&self, max_steps: usize) -> HashMap<(i64, i64), usize> {
+        let start = (self.start.0 as i64, self.start.1 as i64);
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some(((row, column), steps)) = queue.pop_front() {
+            if steps == max_steps {
+                continue;
+            }
+            for direction in Direction::ALL {
+                let next = match direction {
+                    Direction::North => (row - 1, column),
+                    Direction::East => (row, column + 1),
+                    Direction::South => (row + 1, column),
+                    Direction::West => (row, column - 1),
+                };
+                if self.is_plot(next) && !distances.contains_key(&next) {
+                    distances.insert(next, steps + 1);
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// The plots the elf can be on after exactly `steps` steps. Any plot
+    /// reached in fewer steps of the same parity can be returned to by
+    /// stepping back and forth.
+    pub fn reachable_plots(&self, steps: usize) -> usize {
+        Garden::count_reachable(&self.distances(steps), steps)
+    }
+
+    fn count_reachable(distances: &HashMap<(i64, i64), usize>, steps: usize) -> usize {
+        distances
+            .values()
+            .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+            .count()
+    }
+
+    /// The plots reachable after `steps` steps, for step counts too large to
+    /// search. Every map width further the reachable area grows by a fixed
+    /// second difference, so three searches a map width apart are
+    /// extrapolated as a quadratic.
+    pub fn extrapolated_reachable_plots(&self, steps: usize) -> Result<usize> {
+        let period = self.tiles.width();
+        if period != self.tiles.height() {
+            return Err(PuzzleError::invalid_input(
+                "extrapolation needs a square map",
+            ));
+        }
+        let offset = steps % period;
+        let distances = self.distances(offset + 2 * period);
+        let mut samples = (0..3)
+            .map(|index| Garden::count_reachable(&distances, offset + index * period) as i64)
+            .collect::<Vec<_>>();
+        for _ in 2..steps / period {
+            let next = OASISReport::forecast_next_values(&samples);
+            samples.remove(0);
+            samples.push(next);
+        }
+        let index = (steps / period).min(2);
+        Ok(samples[index] as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parser::parse;
+
+    pub(super) const INPUT: &str = r"
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    /// A garden with clear paths out from the start, like the puzzle inputs.
+    pub(super) const CLEAR_INPUT: &str = r"
+...........
+.#.....#.#.
+...#...#...
+.#.....#...
+...##......
+.....S.....
+..#....#...
+....#...#..
+.#.........
+...#...#.#.
+...........";
+
+    #[test]
+    fn reachable_plots_infinite() {
+        let garden = parse(INPUT.trim()).expect("valid parse");
+        assert_eq!(50, garden.reachable_plots(10));
+        assert_eq!(1594, garden.reachable_plots(50));
+        assert_eq!(6536, garden.reachable_plots(100));
+    }
+
+    #[test]
+    fn extrapolated_reachable_plots() {
+        let garden = parse(CLEAR_INPUT.trim()).expect("valid parse");
+        for steps in [5, 16, 27, 60, 115] {
+            assert_eq!(
+                Ok(garden.reachable_plots(steps)),
+                garden.extrapolated_reachable_plots(steps)
+            );
+        }
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+mod part_one {
+    use super::{Garden, Result};
+
+    pub const STEPS: usize = 64;
+
+    pub fn solve(garden: &Garden, steps: usize) -> Result<usize> {
+        Ok(garden.reachable_plots(steps))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let garden = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(16), solve(&garden, 6));
+        }
+    }
+}
+
+mod part_two {
+    use super::{Garden, Result};
+
+    pub const STEPS: usize = 26501365;
+
+    pub fn solve(garden: &Garden, steps: usize) -> Result<usize> {
+        garden.extrapolated_reachable_plots(steps)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::CLEAR_INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let garden = parse(CLEAR_INPUT.trim()).expect("valid parse");
+            // like the puzzle's step count, reaching the edge of a map and
+            // then whole map widths further
+            for steps in [49, 82, 137] {
+                assert_eq!(Ok(garden.reachable_plots(steps)), solve(&garden, steps));
+            }
+        }
+    }
+}
+
+mod parser {
+    use super::{Garden, Grid, Result, Tile};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Garden> {
+        let tiles = Grid::parse_block(input, input, |c| match c {
+            '.' => Some(Tile::Plot),
+            '#' => Some(Tile::Rock),
+            'S' => Some(Tile::Start),
+            _ => None,
+        })?;
+        let [start] = tiles.find(|&tile| tile == Tile::Start).collect::<Vec<_>>()[..] else {
+            return Err(PuzzleError::invalid_input(
+                "expected a single starting position",
+            ));
+        };
+        Ok(Garden { tiles, start })
+    }
 }
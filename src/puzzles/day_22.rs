@@ -1,9 +1,283 @@
-use super::{PuzzleError, Result};
+use std::collections::{HashMap, VecDeque};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Settled;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let snapshot = parser::parse(input)?;
+        Ok(Settled::new(&snapshot))
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let count = part_one::solve(input)?;
+        Ok(count.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
+}
+
+/// A brick's cubes from `start` to `end` inclusive, each coordinate of
+/// `start` no greater than that of `end`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Brick {
+    start: [u32; 3],
+    end: [u32; 3],
+}
+
+impl Brick {
+    fn new(a: [u32; 3], b: [u32; 3]) -> Brick {
+        Brick {
+            start: [0, 1, 2].map(|axis| a[axis].min(b[axis])),
+            end: [0, 1, 2].map(|axis| a[axis].max(b[axis])),
+        }
+    }
+
+    fn bottom(&self) -> u32 {
+        self.start[2]
+    }
+
+    fn top(&self) -> u32 {
+        self.end[2]
+    }
+
+    /// The `(x, y)` columns the brick occupies.
+    fn footprint(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (self.start[0]..=self.end[0])
+            .flat_map(move |x| (self.start[1]..=self.end[1]).map(move |y| (x, y)))
+    }
+
+    fn dropped_to(&self, bottom: u32) -> Brick {
+        let height = self.top() - self.bottom();
+        Brick {
+            start: [self.start[0], self.start[1], bottom],
+            end: [self.end[0], self.end[1], bottom + height],
+        }
+    }
+}
+
+/// The bricks once they have fallen as far as they go, indexed as they were
+/// in the snapshot, and which bricks rest on which.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Settled {
+    bricks: Vec<Brick>,
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+impl Settled {
+    /// Drops the bricks lowest first, tracking the top brick of every column
+    /// in a height map.
+    pub fn new(snapshot: &[Brick]) -> Settled {
+        let mut order = (0..snapshot.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| snapshot[index].bottom());
+
+        let mut bricks = snapshot.to_vec();
+        let mut supports = vec![vec![]; snapshot.len()];
+        let mut supported_by = vec![vec![]; snapshot.len()];
+        let mut heights: HashMap<(u32, u32), (u32, usize)> = HashMap::new();
+        for index in order {
+            let brick = snapshot[index];
+            let below = brick
+                .footprint()
+                .filter_map(|column| heights.get(&column))
+                .copied()
+                .collect::<Vec<_>>();
+            let rest = below.iter().map(|&(top, _)| top).max().unwrap_or(0);
+            let mut resting_on = below
+                .iter()
+                .filter(|&&(top, _)| top == rest)
+                .map(|&(_, below)| below)
+                .collect::<Vec<_>>();
+            resting_on.sort();
+            resting_on.dedup();
+            for &below in &resting_on {
+                supports[below].push(index);
+            }
+            supported_by[index] = resting_on;
+
+            let settled = brick.dropped_to(rest + 1);
+            for column in settled.footprint() {
+                heights.insert(column, (settled.top(), index));
+            }
+            bricks[index] = settled;
+        }
+        Settled {
+            bricks,
+            supports,
+            supported_by,
+        }
+    }
+
+    pub fn bricks(&self) -> &[Brick] {
+        &self.bricks
+    }
+
+    /// The bricks resting directly on brick `index`.
+    pub fn supports(&self, index: usize) -> &[usize] {
+        &self.supports[index]
+    }
+
+    /// The bricks brick `index` rests directly on.
+    pub fn supported_by(&self, index: usize) -> &[usize] {
+        &self.supported_by[index]
+    }
+
+    fn is_safe_to_disintegrate(&self, index: usize) -> bool {
+        self.supports[index]
+            .iter()
+            .all(|&above| self.supported_by[above].len() > 1)
+    }
+
+    /// The number of other bricks that fall when brick `index` is
+    /// disintegrated.
+    fn chain_reaction(&self, index: usize) -> usize {
+        let mut remaining_supports = self.supported_by.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut falling = VecDeque::from([index]);
+        let mut fallen = 0;
+        while let Some(brick) = falling.pop_front() {
+            for &above in &self.supports[brick] {
+                remaining_supports[above] -= 1;
+                if remaining_supports[above] == 0 {
+                    fallen += 1;
+                    falling.push_back(above);
+                }
+            }
+        }
+        fallen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, Settled};
+
+    pub(super) const INPUT: &str = r"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[test]
+    fn settle() {
+        let snapshot = parse(INPUT.trim()).expect("valid parse");
+        let settled = Settled::new(&snapshot);
+        let bottoms = settled
+            .bricks()
+            .iter()
+            .map(|brick| brick.bottom())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 2, 3, 3, 4, 5], bottoms);
+        assert_eq!(&[1, 2], settled.supports(0));
+        assert_eq!(&[3, 4], settled.supports(1));
+        assert_eq!(&[1, 2], settled.supported_by(3));
+        assert_eq!(&[5], settled.supported_by(6));
+        assert!(settled.supports(6).is_empty());
+        assert!(settled.supported_by(0).is_empty());
+    }
+}
+
+mod part_one {
+    use super::{Result, Settled};
+
+    pub fn solve(settled: &Settled) -> Result<usize> {
+        Ok((0..settled.bricks().len())
+            .filter(|&index| settled.is_safe_to_disintegrate(index))
+            .count())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, super::Settled, solve};
+
+        #[test]
+        fn part_one() {
+            let snapshot = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(5), solve(&Settled::new(&snapshot)));
+        }
+    }
+}
+
+mod part_two {
+    use super::{Result, Settled};
+
+    pub fn solve(settled: &Settled) -> Result<usize> {
+        Ok((0..settled.bricks().len())
+            .map(|index| settled.chain_reaction(index))
+            .sum())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, super::Settled, solve};
+
+        #[test]
+        fn part_two() {
+            let snapshot = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(7), solve(&Settled::new(&snapshot)));
+        }
+    }
+}
+
+mod parser {
+    use super::{Brick, Result};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Vec<Brick>> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                parse_brick(line)
+                    .ok_or_else(|| PuzzleError::invalid_input_at(input, line, "invalid brick"))
+            })
+            .collect()
+    }
+
+    fn parse_brick(line: &str) -> Option<Brick> {
+        let (start, end) = line.split_once('~')?;
+        let start = parse_position(start)?;
+        let end = parse_position(end)?;
+        // bricks rest on the ground at z = 0
+        if start[2] == 0 || end[2] == 0 {
+            return None;
+        }
+        Some(Brick::new(start, end))
+    }
+
+    fn parse_position(position: &str) -> Option<[u32; 3]> {
+        let coordinates = position
+            .split(',')
+            .map(|coordinate| coordinate.trim().parse().ok())
+            .collect::<Option<Vec<u32>>>()?;
+        coordinates.try_into().ok()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse;
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_invalid_brick() {
+            assert!(matches!(
+                parse("1,0,1~1,2,1\n0,0~2,0,2"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+            assert!(matches!(
+                parse("1,0,0~1,2,0"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+        }
+    }
 }
@@ -1,9 +1,293 @@
-use super::{PuzzleError, Result};
+use std::collections::HashMap;
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Direction, Grid, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Trails;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let steps = part_one::solve(input)?;
+        Ok(steps.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let steps = part_two::solve(input)?;
+        Ok(steps.into())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Trails {
+    tiles: Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Trails {
+    fn is_open(&self, position: (usize, usize)) -> bool {
+        self.tiles[position] != Tile::Forest
+    }
+
+    /// The directions a hike may leave `position` in, downhill only on
+    /// slopes when they are `slippery`.
+    fn exits(&self, position: (usize, usize), slippery: bool) -> Vec<Direction> {
+        match self.tiles[position] {
+            Tile::Slope(direction) if slippery => vec![direction],
+            _ => Direction::ALL.to_vec(),
+        }
+    }
+
+    /// The start, the end and every tile where trails meet.
+    fn junctions(&self) -> Vec<(usize, usize)> {
+        let mut junctions = vec![self.start, self.end];
+        junctions.extend(self.tiles.positions().filter(|&position| {
+            self.is_open(position)
+                && self
+                    .tiles
+                    .neighbors(position.0, position.1)
+                    .filter(|&neighbor| self.is_open(neighbor))
+                    .count()
+                    > 2
+        }));
+        junctions
+    }
+
+    /// The trails compressed into a graph of junctions, each edge weighted
+    /// by the steps between its junctions.
+    pub fn graph(&self, slippery: bool) -> TrailGraph {
+        let junctions = self.junctions();
+        let indices = junctions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| (position, index))
+            .collect::<HashMap<_, _>>();
+        let mut edges = vec![vec![]; junctions.len()];
+        for (from, &junction) in junctions.iter().enumerate() {
+            for direction in self.exits(junction, slippery) {
+                let Some(mut position) = self.tiles.step(junction, direction) else {
+                    continue;
+                };
+                let mut previous = junction;
+                let mut steps = 1;
+                while self.is_open(position) {
+                    if let Some(&to) = indices.get(&position) {
+                        edges[from].push((to, steps));
+                        break;
+                    }
+                    let next = self
+                        .exits(position, slippery)
+                        .into_iter()
+                        .filter_map(|direction| self.tiles.step(position, direction))
+                        .find(|&next| next != previous && self.is_open(next));
+                    let Some(next) = next else {
+                        break;
+                    };
+                    previous = position;
+                    position = next;
+                    steps += 1;
+                }
+            }
+        }
+        TrailGraph {
+            edges,
+            start: 0,
+            end: 1,
+        }
+    }
+}
+
+/// Junctions and the steps of the trails leading from each to others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailGraph {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+impl TrailGraph {
+    pub fn junction_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// The `(junction, steps)` trails leading from `junction`.
+    pub fn edges(&self, junction: usize) -> &[(usize, usize)] {
+        &self.edges[junction]
+    }
+
+    /// The steps of the longest hike from start to end never visiting a
+    /// junction twice, by a depth first search over visited junction bit
+    /// sets.
+    pub fn longest_hike(&self) -> Result<Option<usize>> {
+        if self.junction_count() > u64::BITS as usize {
+            return Err(PuzzleError::invalid_input(
+                "too many trail junctions to search",
+            ));
+        }
+        // once at the only junction leading to the end, any other way
+        // would cut the end off
+        let exit = (0..self.junction_count())
+            .filter(|&junction| self.edges[junction].iter().any(|&(to, _)| to == self.end))
+            .collect::<Vec<_>>();
+        let exit = match exit[..] {
+            [exit] => Some(exit),
+            _ => None,
+        };
+        Ok(self.search(self.start, 1 << self.start, exit))
+    }
+
+    fn search(&self, junction: usize, visited: u64, exit: Option<usize>) -> Option<usize> {
+        if junction == self.end {
+            return Some(0);
+        }
+        let edges = self.edges[junction]
+            .iter()
+            .filter(|&&(to, _)| exit != Some(junction) || to == self.end);
+        edges
+            .filter(|&&(to, _)| visited & (1 << to) == 0)
+            .filter_map(|&(to, steps)| {
+                self.search(to, visited | (1 << to), exit)
+                    .map(|rest| steps + rest)
+            })
+            .max()
+    }
+}
+
+fn solve(trails: &Trails, slippery: bool) -> Result<usize> {
+    trails
+        .graph(slippery)
+        .longest_hike()?
+        .ok_or_else(|| PuzzleError::invalid_input("no hike reaches the end"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parser::parse;
+
+    pub(super) const INPUT: &str = r"
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn graph() {
+        let trails = parse(INPUT.trim()).expect("valid parse");
+        let graph = trails.graph(false);
+        assert_eq!(9, graph.junction_count());
+        // the start leads only to the first junction, 15 steps away
+        assert_eq!(1, graph.edges(0).len());
+        assert_eq!(15, graph.edges(0)[0].1);
+
+        let slippery = trails.graph(true);
+        let trail_count = |graph: &super::TrailGraph| {
+            (0..graph.junction_count())
+                .map(|junction| graph.edges(junction).len())
+                .sum::<usize>()
+        };
+        assert!(trail_count(&slippery) < trail_count(&graph));
+    }
+}
+
+mod part_one {
+    use super::{solve as longest_hike, Result, Trails};
+
+    pub fn solve(trails: &Trails) -> Result<usize> {
+        longest_hike(trails, true)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let trails = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(94), solve(&trails));
+        }
+    }
+}
+
+mod part_two {
+    use super::{solve as longest_hike, Result, Trails};
+
+    pub fn solve(trails: &Trails) -> Result<usize> {
+        longest_hike(trails, false)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let trails = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(154), solve(&trails));
+        }
+    }
+}
+
+mod parser {
+    use super::{Direction, Grid, Result, Tile, Trails};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Trails> {
+        let tiles = Grid::parse_block(input, input, |c| match c {
+            '.' => Some(Tile::Path),
+            '#' => Some(Tile::Forest),
+            '^' => Some(Tile::Slope(Direction::North)),
+            '>' => Some(Tile::Slope(Direction::East)),
+            'v' => Some(Tile::Slope(Direction::South)),
+            '<' => Some(Tile::Slope(Direction::West)),
+            _ => None,
+        })?;
+        let last_row = tiles.height() - 1;
+        let opening = |row: usize| {
+            let [column] = tiles
+                .find(|&tile| tile == Tile::Path)
+                .filter(|&(path_row, _)| path_row == row)
+                .map(|(_, column)| column)
+                .collect::<Vec<_>>()[..]
+            else {
+                return Err(PuzzleError::invalid_input(
+                    "expected a single path tile in the top and bottom rows",
+                ));
+            };
+            Ok((row, column))
+        };
+        let start = opening(0)?;
+        let end = opening(last_row)?;
+        Ok(Trails { tiles, start, end })
+    }
 }
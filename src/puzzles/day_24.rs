@@ -1,9 +1,302 @@
-use super::{PuzzleError, Result};
+use num::{BigInt, BigRational, Signed, Zero};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Hailstone>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let count = part_one::solve(input, part_one::TEST_AREA)?;
+        Ok(count.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let sum = part_two::solve(input)?;
+        Ok(sum.into())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Hailstone {
+    position: [i64; 3],
+    velocity: [i64; 3],
+}
+
+fn rational(value: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(value))
+}
+
+impl Hailstone {
+    /// Where the paths of two hailstones cross in the x-y plane, ignoring z,
+    /// if they cross at a time in the future for both.
+    fn crossing_xy(&self, other: &Hailstone) -> Option<(BigRational, BigRational)> {
+        let [px, py, _] = self.position.map(rational);
+        let [vx, vy, _] = self.velocity.map(rational);
+        let [qx, qy, _] = other.position.map(rational);
+        let [wx, wy, _] = other.velocity.map(rational);
+        let determinant = &vx * &wy - &vy * &wx;
+        if determinant.is_zero() {
+            return None;
+        }
+        let (dx, dy) = (&qx - &px, &qy - &py);
+        let t = (&dx * &wy - &dy * &wx) / &determinant;
+        let s = (&dx * &vy - &dy * &vx) / &determinant;
+        if t.is_negative() || s.is_negative() {
+            return None;
+        }
+        Some((px + vx * &t, py + vy * t))
+    }
+}
+
+fn cross<T: Copy + std::ops::Mul<Output = T> + std::ops::Sub<Output = T>>(
+    a: [T; 3],
+    b: [T; 3],
+) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn difference(a: [i64; 3], b: [i64; 3]) -> [i64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// The six equations in rock position P and velocity V from the rock's path
+/// crossing hailstone `i` and `j`: (P - p) x (V - v) = 0 for each, whose
+/// difference cancels the non-linear P x V term.
+fn rock_equations(
+    hailstones: &[Hailstone],
+    i: usize,
+    j: usize,
+) -> Vec<(Vec<BigRational>, BigRational)> {
+    let (a, b) = (&hailstones[i], &hailstones[j]);
+    // P x w + u x V = p_j x v_j - p_i x v_i
+    let w = difference(b.velocity, a.velocity);
+    let u = difference(b.position, a.position);
+    let momentum = |hailstone: &Hailstone| {
+        cross(
+            hailstone.position.map(i128::from),
+            hailstone.velocity.map(i128::from),
+        )
+    };
+    let (pb, pa) = (momentum(b), momentum(a));
+    let constants = [0, 1, 2].map(|axis| pb[axis] - pa[axis]);
+    let rows = [
+        [0, w[2], -w[1], 0, -u[2], u[1]],
+        [-w[2], 0, w[0], u[2], 0, -u[0]],
+        [w[1], -w[0], 0, -u[1], u[0], 0],
+    ];
+    rows.into_iter()
+        .zip(constants)
+        .map(|(row, constant)| {
+            (
+                row.into_iter().map(rational).collect(),
+                BigRational::from_integer(BigInt::from(constant)),
+            )
+        })
+        .collect()
+}
+
+/// Solves a square linear system exactly by Gaussian elimination, or `None`
+/// if it is singular.
+fn solve_linear(mut equations: Vec<(Vec<BigRational>, BigRational)>) -> Option<Vec<BigRational>> {
+    let size = equations.len();
+    for column in 0..size {
+        let pivot = (column..size).find(|&row| !equations[row].0[column].is_zero())?;
+        equations.swap(column, pivot);
+        let (coefficients, constant) = equations[column].clone();
+        for (row, (targets, target_constant)) in equations.iter_mut().enumerate() {
+            if row == column || targets[column].is_zero() {
+                continue;
+            }
+            let factor = &targets[column] / &coefficients[column];
+            for (target, value) in targets.iter_mut().zip(&coefficients) {
+                *target -= &factor * value;
+            }
+            *target_constant -= &factor * &constant;
+        }
+    }
+    Some(
+        equations
+            .into_iter()
+            .enumerate()
+            .map(|(index, (coefficients, constant))| constant / &coefficients[index])
+            .collect(),
+    )
+}
+
+/// The integer position the rock is thrown from to hit every hailstone.
+fn rock_position(hailstones: &[Hailstone]) -> Result<[i64; 3]> {
+    // three hailstones determine the rock unless their equations are
+    // degenerate, and any that aren't give the same rock
+    for k in 2..hailstones.len() {
+        let mut equations = rock_equations(hailstones, 0, 1);
+        equations.extend(rock_equations(hailstones, 0, k));
+        let Some(solution) = solve_linear(equations) else {
+            continue;
+        };
+        let position = solution[..3]
+            .iter()
+            .map(|value| {
+                value
+                    .is_integer()
+                    .then(|| value.to_integer().try_into().ok())
+                    .flatten()
+            })
+            .collect::<Option<Vec<i64>>>()
+            .ok_or_else(|| PuzzleError::invalid_input("the rock has no integer position"))?;
+        return Ok([position[0], position[1], position[2]]);
+    }
+    Err(PuzzleError::invalid_input(
+        "hailstones do not determine a rock throw",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parser::parse, rock_position};
+    use crate::PuzzleError;
+
+    pub(super) const INPUT: &str = r"
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn rock_position_sample() {
+        let hailstones = parse(INPUT.trim()).expect("valid parse");
+        assert_eq!(Ok([24, 13, 10]), rock_position(&hailstones));
+    }
+
+    #[test]
+    fn rock_position_fractional() {
+        // hit at times 1/2, 3/2, 5/2 and 7/2 by a rock thrown from (1/2, 1, 3/2)
+        let hailstones = parse(
+            "0, 2, 4 @ 2, 0, -2\n2, -2, 3 @ 0, 4, 2\n8, 1, 9 @ -2, 2, 0\n-10, 15, -9 @ 4, -2, 6",
+        )
+        .expect("valid parse");
+        assert_eq!(
+            Err(PuzzleError::invalid_input(
+                "the rock has no integer position"
+            )),
+            rock_position(&hailstones)
+        );
+    }
+
+    #[test]
+    fn rock_position_degenerate() {
+        // hailstones moving together are hit by any rock thrown along them
+        let hailstones =
+            parse("0, 0, 0 @ 1, 1, 1\n1, 0, 0 @ 1, 1, 1\n0, 1, 0 @ 1, 1, 1").expect("valid parse");
+        assert_eq!(
+            Err(PuzzleError::invalid_input(
+                "hailstones do not determine a rock throw"
+            )),
+            rock_position(&hailstones)
+        );
+    }
+}
+
+mod part_one {
+    use std::ops::RangeInclusive;
+
+    use num::BigRational;
+
+    use super::{rational, Hailstone, Result};
+
+    pub const TEST_AREA: RangeInclusive<i64> = 200000000000000..=400000000000000;
+
+    /// The pairs of hailstones whose paths cross within `area` in both x and y.
+    pub fn solve(hailstones: &[Hailstone], area: RangeInclusive<i64>) -> Result<usize> {
+        let (min, max) = (rational(*area.start()), rational(*area.end()));
+        let within = |value: &BigRational| &min <= value && value <= &max;
+        Ok(hailstones
+            .iter()
+            .enumerate()
+            .flat_map(|(index, a)| hailstones[index + 1..].iter().map(move |b| (a, b)))
+            .filter_map(|(a, b)| a.crossing_xy(b))
+            .filter(|(x, y)| within(x) && within(y))
+            .count())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let hailstones = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(2), solve(&hailstones, 7..=27));
+        }
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+mod part_two {
+    use super::{rock_position, Hailstone, Result};
+
+    pub fn solve(hailstones: &[Hailstone]) -> Result<i64> {
+        Ok(rock_position(hailstones)?.iter().sum())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_two() {
+            let hailstones = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(47), solve(&hailstones));
+        }
+    }
+}
+
+mod parser {
+    use super::{Hailstone, Result};
+    use crate::{parser::HasNumbers, PuzzleError};
+
+    pub fn parse(input: &str) -> Result<Vec<Hailstone>> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                parse_hailstone(line)
+                    .ok_or_else(|| PuzzleError::invalid_input_at(input, line, "invalid hailstone"))
+            })
+            .collect()
+    }
+
+    fn parse_hailstone(line: &str) -> Option<Hailstone> {
+        let (position, velocity) = line.split_once('@')?;
+        let position = position.signed_numbers().collect::<Vec<i64>>();
+        let velocity = velocity.signed_numbers().collect::<Vec<i64>>();
+        Some(Hailstone {
+            position: position.try_into().ok()?,
+            velocity: velocity.try_into().ok()?,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse;
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_invalid_hailstone() {
+            assert!(matches!(
+                parse("19, 13, 30 @ -2, 1"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+        }
+    }
 }
@@ -1,9 +1,232 @@
-use super::{PuzzleError, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-pub fn run_part_one() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+use crate::{Answer, Part, PuzzleError, Result, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Wiring;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parser::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let product = part_one::solve(input)?;
+        Ok(product.into())
+    }
+
+    fn part_two(&self, _input: &Self::Input) -> Result<Answer> {
+        // never run since the part isn't listed in `parts`
+        Err(PuzzleError::NotImplemented)
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        // the last day has no second puzzle
+        &[Part::One]
+    }
+}
+
+const CUT_WIRES: usize = 3;
+
+/// Components and the undirected wires between them, by component index.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Wiring {
+    names: Vec<String>,
+    wires: Vec<Vec<usize>>,
+}
+
+/// Wires whose removal splits the components in two, and the components on
+/// one side.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cut {
+    pub wires: Vec<(String, String)>,
+    pub group: Vec<String>,
+}
+
+impl Wiring {
+    /// The components reachable from `source` in the residual graph once no
+    /// more than `limit` wire-disjoint paths lead to `sink`, or `None` if more
+    /// do. Each wire carries one unit of flow in either direction.
+    fn source_side(&self, source: usize, sink: usize, limit: usize) -> Option<HashSet<usize>> {
+        let mut flow: HashMap<(usize, usize), i32> = HashMap::new();
+        let residual = |flow: &HashMap<(usize, usize), i32>, from: usize, to: usize| {
+            1 - flow.get(&(from, to)).copied().unwrap_or(0)
+        };
+        for _ in 0..=limit {
+            let mut previous = HashMap::from([(source, source)]);
+            let mut queue = VecDeque::from([source]);
+            while let Some(component) = queue.pop_front() {
+                if component == sink {
+                    break;
+                }
+                for &next in &self.wires[component] {
+                    if residual(&flow, component, next) > 0 && !previous.contains_key(&next) {
+                        previous.insert(next, component);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            if !previous.contains_key(&sink) {
+                return Some(previous.into_keys().collect());
+            }
+            let mut component = sink;
+            while component != source {
+                let prior = previous[&component];
+                *flow.entry((prior, component)).or_default() += 1;
+                *flow.entry((component, prior)).or_default() -= 1;
+                component = prior;
+            }
+        }
+        None
+    }
+
+    /// A cut of at most `size` wires, found by max-flow from the first
+    /// component to each other component in turn until one lies across it.
+    pub fn cut(&self, size: usize) -> Option<Cut> {
+        let group = (1..self.names.len()).find_map(|sink| self.source_side(0, sink, size))?;
+        let mut wires = group
+            .iter()
+            .flat_map(|&from| {
+                self.wires[from]
+                    .iter()
+                    .filter(|to| !group.contains(to))
+                    .map(move |&to| (self.names[from].clone(), self.names[to].clone()))
+            })
+            .collect::<Vec<_>>();
+        wires.sort();
+        let mut group = group
+            .into_iter()
+            .map(|component| self.names[component].clone())
+            .collect::<Vec<_>>();
+        group.sort();
+        Some(Cut { wires, group })
+    }
+
+    fn component_count(&self) -> usize {
+        self.names.len()
+    }
 }
 
-pub fn run_part_two() -> Result<()> {
-    Err(PuzzleError::NotImplemented)
+#[cfg(test)]
+mod tests {
+    use super::parser::parse;
+
+    pub(super) const INPUT: &str = r"
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn cut() {
+        let wiring = parse(INPUT.trim()).expect("valid parse");
+        let cut = wiring.cut(3).expect("three wire cut");
+        let mut wires = cut
+            .wires
+            .iter()
+            .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+            .map(|(a, b)| format!("{a}/{b}"))
+            .collect::<Vec<_>>();
+        wires.sort();
+        assert_eq!(vec!["bvb/cmg", "hfx/pzl", "jqt/nvd"], wires);
+        assert!([6, 9].contains(&cut.group.len()));
+        assert_eq!(None, wiring.cut(2));
+    }
+}
+
+mod part_one {
+    use super::{PuzzleError, Result, Wiring, CUT_WIRES};
+
+    pub fn solve(wiring: &Wiring) -> Result<usize> {
+        let cut = wiring.cut(CUT_WIRES).ok_or_else(|| {
+            PuzzleError::invalid_input("no three wires split the components in two")
+        })?;
+        let group = cut.group.len();
+        Ok(group * (wiring.component_count() - group))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{super::parser::parse, super::tests::INPUT, solve};
+
+        #[test]
+        fn part_one() {
+            let wiring = parse(INPUT.trim()).expect("valid parse");
+            assert_eq!(Ok(54), solve(&wiring));
+        }
+    }
+}
+
+mod parser {
+    use std::collections::HashMap;
+
+    use super::{Result, Wiring};
+    use crate::PuzzleError;
+
+    pub fn parse(input: &str) -> Result<Wiring> {
+        let mut indices = HashMap::new();
+        let mut wiring = Wiring {
+            names: vec![],
+            wires: vec![],
+        };
+        let mut index = |wiring: &mut Wiring, name: &str| {
+            *indices.entry(name.to_owned()).or_insert_with(|| {
+                wiring.names.push(name.to_owned());
+                wiring.wires.push(vec![]);
+                wiring.names.len() - 1
+            })
+        };
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (component, connected) = line
+                .split_once(':')
+                .filter(|(component, connected)| {
+                    is_name(component) && connected.split_whitespace().all(is_name)
+                })
+                .ok_or_else(|| {
+                    PuzzleError::invalid_input_at(input, line, "expected 'component: components'")
+                })?;
+            let from = index(&mut wiring, component);
+            for other in connected.split_whitespace() {
+                let to = index(&mut wiring, other);
+                if !wiring.wires[from].contains(&to) {
+                    wiring.wires[from].push(to);
+                    wiring.wires[to].push(from);
+                }
+            }
+        }
+        if wiring.names.len() < 2 {
+            return Err(PuzzleError::invalid_input(
+                "expected at least two components",
+            ));
+        }
+        Ok(wiring)
+    }
+
+    fn is_name(name: &str) -> bool {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse;
+        use crate::PuzzleError;
+
+        #[test]
+        fn parse_invalid_line() {
+            assert!(matches!(
+                parse("jqt: rhn xhk\nrsh frs"),
+                Err(PuzzleError::InvalidInputAt { .. })
+            ));
+        }
+    }
 }
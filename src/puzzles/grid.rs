@@ -0,0 +1,235 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{PuzzleError, Result};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+}
+
+/// A rectangular grid of cells addressed by `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses the lines of `block`, a slice of `input`, into a grid of cells.
+    /// Characters rejected by `cell` and ragged rows are reported at their
+    /// location in `input`.
+    pub(crate) fn parse_block<F>(input: &str, block: &str, cell: F) -> Result<Grid<T>>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in block.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let row_start = cells.len();
+            for (index, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    let text = &line[index..index + c.len_utf8()];
+                    PuzzleError::invalid_input_at(input, text, "invalid grid cell")
+                })?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(PuzzleError::invalid_input_at(
+                    input,
+                    line,
+                    "grid rows must have the same width",
+                ));
+            }
+            height += 1;
+        }
+        if height == 0 {
+            return Err(PuzzleError::invalid_input("empty grid"));
+        }
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.height && column < self.width {
+            Some(&self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// All `(row, column)` positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// The positions of the cells matching `predicate`.
+    pub fn find<'a, P>(&'a self, predicate: P) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        P: Fn(&T) -> bool + 'a,
+    {
+        self.positions()
+            .filter(move |&(row, column)| predicate(&self[(row, column)]))
+    }
+
+    /// The in-grid position one step in `direction` from a position.
+    pub fn step(
+        &self,
+        (row, column): (usize, usize),
+        direction: Direction,
+    ) -> Option<(usize, usize)> {
+        match direction {
+            Direction::North => row.checked_sub(1).map(|row| (row, column)),
+            Direction::East => (column + 1 < self.width).then_some((row, column + 1)),
+            Direction::South => (row + 1 < self.height).then_some((row + 1, column)),
+            Direction::West => column.checked_sub(1).map(|column| (row, column)),
+        }
+    }
+
+    /// The in-grid positions north, east, south and west of a position.
+    pub fn neighbors(&self, row: usize, column: usize) -> impl Iterator<Item = (usize, usize)> {
+        let steps = Direction::ALL.map(|direction| self.step((row, column), direction));
+        steps.into_iter().flatten()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(column < self.width, "column {column} out of the grid");
+        &self.cells[row * self.width + column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(column < self.width, "column {column} out of the grid");
+        &mut self.cells[row * self.width + column]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::PuzzleError;
+
+    fn rocks(input: &str) -> crate::Result<Grid<bool>> {
+        Grid::parse_block(input, input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn parse() {
+        let grid = rocks("#..\n.#.\n").expect("valid grid");
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(&[false, true, false], grid.row(1));
+        assert_eq!(vec![&false, &true], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(Some(&true), grid.get(0, 0));
+        assert_eq!(None, grid.get(2, 0));
+        assert_eq!(
+            vec![(0, 0), (1, 1)],
+            grid.find(|&rock| rock).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 1), (1, 2), (1, 0)],
+            grid.neighbors(1, 1).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_ragged() {
+        assert!(matches!(
+            rocks("#..\n.#"),
+            Err(PuzzleError::InvalidInputAt { .. })
+        ));
+        assert!(matches!(
+            rocks("#.x"),
+            Err(PuzzleError::InvalidInputAt { .. })
+        ));
+    }
+}
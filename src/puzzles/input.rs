@@ -0,0 +1,215 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use log::debug;
+use serde::Deserialize;
+
+use crate::{PuzzleError, Result};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+const CONFIG_DIR_NAME: &str = "aoc_2023";
+const EMBEDDED_LOCATION: &str = "<embedded inputs>";
+
+include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+pub enum InputSource {
+    Default,
+    Directory(PathBuf),
+    Path(PathBuf),
+    Text(String),
+}
+
+impl InputSource {
+    pub(crate) fn read(&self, day: i32) -> Result<String> {
+        match self {
+            InputSource::Default => read_input_file(&SearchPath::new(None)?, day),
+            InputSource::Directory(dir) => {
+                read_input_file(&SearchPath::new(Some(dir.to_owned()))?, day)
+            }
+            InputSource::Path(path) => read_input_path(path),
+            InputSource::Text(text) => Ok(text.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    input_dir: Option<PathBuf>,
+}
+
+/// The ordered directories searched for `day_XX.input` files: the given
+/// directory, `AOC_INPUT_DIR`, the `input_dir` of the first `aoc.toml` found in
+/// the working or XDG config directory and the executable's directory. Inputs
+/// compiled in with the `embed-inputs` feature are used when none of the
+/// directories has the file.
+#[derive(Debug, PartialEq)]
+pub struct SearchPath {
+    directories: Vec<PathBuf>,
+}
+
+impl SearchPath {
+    pub fn new(input_dir: Option<PathBuf>) -> Result<SearchPath> {
+        let mut directories = vec![];
+        directories.extend(input_dir);
+        if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+            directories.push(PathBuf::from(dir));
+        }
+        if let Some(dir) = config_input_dir()? {
+            directories.push(dir);
+        }
+        match env::current_exe() {
+            Ok(exe_path) => directories.extend(exe_path.parent().map(Path::to_path_buf)),
+            Err(err) => debug!("failed to get executable path: {err}"),
+        }
+        Ok(SearchPath { directories })
+    }
+
+    pub fn directories(&self) -> &[PathBuf] {
+        &self.directories
+    }
+
+    fn read(&self, file_name: &str) -> Result<String> {
+        let mut searched = vec![];
+        for dir in &self.directories {
+            let path = dir.join(file_name);
+            match fs::read_to_string(&path) {
+                Ok(string) => return Ok(string),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    debug!("input file '{}' not found", path.display());
+                    searched.push(path.to_string_lossy().to_string());
+                }
+                Err(err) => return Err(PuzzleError::from_io_error(&path, err)),
+            }
+        }
+        if let Some(input) = embedded_input(file_name) {
+            return Ok(input.to_owned());
+        }
+        if cfg!(feature = "embed-inputs") {
+            searched.push(EMBEDDED_LOCATION.to_owned());
+        }
+        Err(PuzzleError::LoadInputFailure {
+            path: file_name.to_owned(),
+            source: io::Error::new(io::ErrorKind::NotFound, "input file not found"),
+            searched,
+        })
+    }
+}
+
+fn config_input_dir() -> Result<Option<PathBuf>> {
+    let config_dirs = env::current_dir().into_iter().chain(
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+            .map(|dir| dir.join(CONFIG_DIR_NAME)),
+    );
+    for dir in config_dirs {
+        let path = dir.join(CONFIG_FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(contents) => return config_input_dir_from(&path, &contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(PuzzleError::from_io_error(&path, err)),
+        }
+    }
+    Ok(None)
+}
+
+fn config_input_dir_from(path: &Path, contents: &str) -> Result<Option<PathBuf>> {
+    let config: Config = toml::from_str(contents).map_err(|err| {
+        PuzzleError::unexpected_err(
+            &format!("invalid config file '{}'", path.display()),
+            err.into(),
+        )
+    })?;
+    // relative input directories are relative to the config file
+    Ok(config.input_dir.map(|input_dir| match path.parent() {
+        Some(config_dir) => config_dir.join(input_dir),
+        None => input_dir,
+    }))
+}
+
+fn read_input_file(search_path: &SearchPath, day: i32) -> Result<String> {
+    search_path.read(&format!("day_{day:02}.input"))
+}
+
+fn read_input_path(path: &Path) -> Result<String> {
+    match fs::read_to_string(path) {
+        Ok(string) => Ok(string),
+        Err(err) => Err(PuzzleError::from_io_error(path, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use super::{config_input_dir_from, read_input_file, PuzzleError, SearchPath};
+
+    /// An empty directory unique to the test and process, removed on drop.
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(test: &str) -> TestDir {
+            let dir = env::temp_dir().join(format!("aoc_2023_{}_{test}", process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            TestDir(dir)
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn search_path_first_found() {
+        let dir = TestDir::new("search_path_first_found");
+        let (first, second) = (dir.0.join("first"), dir.0.join("second"));
+        fs::create_dir_all(&first).expect("create first dir");
+        fs::create_dir_all(&second).expect("create second dir");
+        fs::write(second.join("day_01.input"), "second").expect("write input");
+        let search_path = SearchPath {
+            directories: vec![first, second],
+        };
+        assert_eq!(Ok("second".to_owned()), read_input_file(&search_path, 1));
+    }
+
+    #[test]
+    fn search_path_lists_searched_locations() {
+        let missing = TestDir::new("search_path_lists_searched_locations");
+        let missing = &missing.0;
+        let search_path = SearchPath {
+            directories: vec![missing.join("a"), missing.join("b")],
+        };
+        let err = read_input_file(&search_path, 2).expect_err("missing input");
+        assert!(matches!(err, PuzzleError::LoadInputFailure { .. }));
+        let message = err.to_string();
+        for dir in ["a", "b"] {
+            let path = missing.join(dir).join("day_02.input");
+            assert!(message.contains(path.to_string_lossy().as_ref()));
+        }
+    }
+
+    #[test]
+    fn config_relative_input_dir() {
+        let path = Path::new("/home/elf/.config/aoc_2023/aoc.toml");
+        assert_eq!(
+            Ok(Some(
+                Path::new("/home/elf/.config/aoc_2023/inputs").to_path_buf()
+            )),
+            config_input_dir_from(path, r#"input_dir = "inputs""#)
+        );
+        assert_eq!(
+            Ok(Some(Path::new("/srv/aoc").to_path_buf())),
+            config_input_dir_from(path, r#"input_dir = "/srv/aoc""#)
+        );
+        assert_eq!(Ok(None), config_input_dir_from(path, ""));
+    }
+}
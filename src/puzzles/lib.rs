@@ -1,5 +1,4 @@
-use std::env;
-use std::path::Path;
+use std::fmt::Display;
 
 pub mod day_01;
 pub mod day_02;
@@ -27,40 +26,57 @@ pub mod day_23;
 pub mod day_24;
 pub mod day_25;
 
+pub(crate) mod answer;
+pub(crate) mod answers;
+pub(crate) mod grid;
+pub(crate) mod input;
 pub(crate) mod parser;
+pub(crate) mod polygon;
 pub(crate) mod result;
+pub(crate) mod solution;
+pub(crate) mod span;
+pub(crate) mod timing;
 
+pub use answer::Answer;
+pub use answers::{KnownAnswers, Verification, ANSWERS_FILE_NAME};
+pub use grid::{Direction, Grid};
+pub use input::{InputSource, SearchPath, CONFIG_FILE_NAME, INPUT_DIR_VAR};
+pub use result::PuzzleError;
 use result::*;
+pub use solution::Solution;
+use solution::{AnyInput, AnySolution};
+pub use span::Span;
+use timing::timed;
+pub use timing::{PartRun, PuzzleRun, Stats};
 
-type Fn = fn() -> Result<()>;
-
-#[allow(clippy::type_complexity)]
-const PUZZLES: [(&str, Fn, Fn); 25] = [
-    ("one", day_01::run_part_one, day_01::run_part_two),
-    ("two", day_02::run_part_one, day_02::run_part_two),
-    ("three", day_03::run_part_one, day_03::run_part_two),
-    ("four", day_04::run_part_one, day_04::run_part_two),
-    ("five", day_05::run_part_one, day_05::run_part_two),
-    ("six", day_06::run_part_one, day_06::run_part_two),
-    ("seven", day_07::run_part_one, day_07::run_part_two),
-    ("eight", day_08::run_part_one, day_08::run_part_two),
-    ("nine", day_09::run_part_one, day_09::run_part_two),
-    ("ten", day_10::run_part_one, day_10::run_part_two),
-    ("eleven", day_11::run_part_one, day_11::run_part_two),
-    ("twelve", day_12::run_part_one, day_12::run_part_two),
-    ("thirteen", day_13::run_part_one, day_13::run_part_two),
-    ("fourteen", day_14::run_part_one, day_14::run_part_two),
-    ("fifteen", day_15::run_part_one, day_15::run_part_two),
-    ("sixteen", day_16::run_part_one, day_16::run_part_two),
-    ("seventeen", day_17::run_part_one, day_17::run_part_two),
-    ("eighteen", day_18::run_part_one, day_18::run_part_two),
-    ("nineteen", day_19::run_part_one, day_19::run_part_two),
-    ("twenty", day_20::run_part_one, day_20::run_part_two),
-    ("twenty-one", day_21::run_part_one, day_21::run_part_two),
-    ("twenty-two", day_22::run_part_one, day_22::run_part_two),
-    ("twenty-three", day_23::run_part_one, day_23::run_part_two),
-    ("twenty-four", day_24::run_part_one, day_24::run_part_two),
-    ("twenty-five", day_25::run_part_one, day_25::run_part_two),
+type Registration = (&'static str, Option<&'static dyn AnySolution>);
+
+const PUZZLES: [Registration; 25] = [
+    ("one", Some(&day_01::Solver)),
+    ("two", Some(&day_02::Solver)),
+    ("three", Some(&day_03::Solver)),
+    ("four", Some(&day_04::Solver)),
+    ("five", Some(&day_05::Solver)),
+    ("six", Some(&day_06::Solver)),
+    ("seven", Some(&day_07::Solver)),
+    ("eight", Some(&day_08::Solver)),
+    ("nine", Some(&day_09::Solver)),
+    ("ten", Some(&day_10::Solver)),
+    ("eleven", Some(&day_11::Solver)),
+    ("twelve", Some(&day_12::Solver)),
+    ("thirteen", Some(&day_13::Solver)),
+    ("fourteen", Some(&day_14::Solver)),
+    ("fifteen", Some(&day_15::Solver)),
+    ("sixteen", Some(&day_16::Solver)),
+    ("seventeen", Some(&day_17::Solver)),
+    ("eighteen", Some(&day_18::Solver)),
+    ("nineteen", Some(&day_19::Solver)),
+    ("twenty", Some(&day_20::Solver)),
+    ("twenty-one", Some(&day_21::Solver)),
+    ("twenty-two", Some(&day_22::Solver)),
+    ("twenty-three", Some(&day_23::Solver)),
+    ("twenty-four", Some(&day_24::Solver)),
+    ("twenty-five", Some(&day_25::Solver)),
 ];
 
 pub fn puzzle_names() -> Vec<&'static str> {
@@ -70,59 +86,190 @@ pub fn puzzle_names() -> Vec<&'static str> {
 pub fn puzzles() -> Vec<Puzzle> {
     PUZZLES
         .iter()
-        .map(|puzzle| Puzzle::new(puzzle.0, &puzzle.1, &puzzle.2))
+        .enumerate()
+        .map(|(index, puzzle)| Puzzle::new(index as i32 + 1, puzzle.0, puzzle.1))
         .collect()
 }
 
 pub fn get_puzzle(name: &str) -> Option<Puzzle> {
-    PUZZLES.iter().find_map(|puzzle| match puzzle.0 == name {
-        true => Some(Puzzle::new(puzzle.0, &puzzle.1, &puzzle.2)),
-        false => None,
-    })
+    puzzles().into_iter().find(|puzzle| puzzle.name == name)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Part::One => "one",
+            Part::Two => "two",
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
 }
 
 pub struct Puzzle {
+    day: i32,
     name: &'static str,
-    part_one: &'static Fn,
-    part_two: &'static Fn,
+    solution: Option<&'static dyn AnySolution>,
 }
 
 impl Puzzle {
-    fn new(name: &'static str, part_one: &'static Fn, part_two: &'static Fn) -> Self {
+    fn new(day: i32, name: &'static str, solution: Option<&'static dyn AnySolution>) -> Self {
         Puzzle {
+            day,
             name,
-            part_one,
-            part_two,
+            solution,
         }
     }
 
+    pub fn day(&self) -> i32 {
+        self.day
+    }
+
     pub fn name(&self) -> &str {
         self.name
     }
 
-    pub fn run_part_one(&self) -> Result<()> {
-        (*self.part_one)()
+    pub fn is_implemented(&self) -> bool {
+        self.solution.is_some()
+    }
+
+    /// The parts the puzzle has, both for a puzzle not implemented yet.
+    pub fn parts(&self) -> &'static [Part] {
+        self.solution
+            .map_or(&[Part::One, Part::Two], |solution| solution.parts())
+    }
+
+    pub fn has_part(&self, part: Part) -> bool {
+        self.parts().contains(&part)
+    }
+
+    pub fn read_input(&self, source: &InputSource) -> Result<String> {
+        source.read(self.day)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<ParsedPuzzle> {
+        let solution = self.solution.ok_or(PuzzleError::NotImplemented)?;
+        let input = solution.parse(input)?;
+        Ok(ParsedPuzzle { solution, input })
+    }
+
+    pub fn run_part_one(&self, source: &InputSource) -> Result<Answer> {
+        self.load(source)?.part_one()
+    }
+
+    pub fn run_part_two(&self, source: &InputSource) -> Result<Answer> {
+        self.load(source)?.part_two()
+    }
+
+    pub fn load(&self, source: &InputSource) -> Result<ParsedPuzzle> {
+        if !self.is_implemented() {
+            return Err(PuzzleError::NotImplemented);
+        }
+        self.parse(&self.read_input(source)?)
     }
 
-    pub fn run_part_two(&self) -> Result<()> {
-        (*self.part_two)()
+    /// Loads and parses the input then runs the given parts the puzzle has,
+    /// timing each phase. Load and parse failures fail the run; part failures
+    /// are kept per part.
+    pub fn run(&self, source: &InputSource, parts: &[Part]) -> Result<PuzzleRun> {
+        if !self.is_implemented() {
+            return Err(PuzzleError::NotImplemented);
+        }
+        let (input, load) = timed(|| self.read_input(source));
+        let (parsed, parse) = timed(|| self.parse(&input?));
+        let parsed = parsed?;
+        let parts = parts
+            .iter()
+            .filter(|&&part| self.has_part(part))
+            .map(|&part| {
+                let (answer, duration) = timed(|| parsed.part(part));
+                PartRun {
+                    part,
+                    answer,
+                    duration,
+                }
+            })
+            .collect();
+        Ok(PuzzleRun { load, parse, parts })
     }
 }
 
-fn read_input_file(day: i32, part: Option<i32>) -> Result<String> {
-    let data_file_name = if let Some(part) = part {
-        format!("day_{day:02}.part_{part:02}.input")
-    } else {
-        format!("day_{day:02}.input")
-    };
-    let exe_path =
-        env::current_exe().map_err(|err| PuzzleError::from_io_error(&data_file_name, err))?;
-    let exe_dir_path = exe_path
-        .parent()
-        .ok_or_else(|| PuzzleError::unexpected("failed to get executable parent path"))?;
-    let path = Path::new(exe_dir_path).join(data_file_name);
-    match std::fs::read_to_string(&path) {
-        Ok(string) => Ok(string),
-        Err(err) => Err(PuzzleError::from_io_error(&path, err)),
+pub struct ParsedPuzzle {
+    solution: &'static dyn AnySolution,
+    input: AnyInput,
+}
+
+impl ParsedPuzzle {
+    pub fn part_one(&self) -> Result<Answer> {
+        self.solution.part_one(&self.input)
+    }
+
+    pub fn part_two(&self) -> Result<Answer> {
+        self.solution.part_two(&self.input)
+    }
+
+    pub fn part(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(),
+            Part::Two => self.part_two(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_puzzle, Answer, InputSource, Part, PuzzleError};
+
+    const INPUT: &str = r"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    #[test]
+    fn run_part_one_with_text() {
+        let puzzle = get_puzzle("one").expect("puzzle one");
+        let source = InputSource::Text(INPUT.to_owned());
+        assert_eq!(Ok(Answer::Unsigned(142)), puzzle.run_part_one(&source));
+    }
+
+    #[test]
+    fn run_part_one_with_missing_path() {
+        let puzzle = get_puzzle("one").expect("puzzle one");
+        let source = InputSource::Path("missing/day_01.input".into());
+        assert!(matches!(
+            puzzle.run_part_one(&source),
+            Err(PuzzleError::LoadInputFailure { .. })
+        ));
+    }
+
+    #[test]
+    fn run_times_each_part() {
+        let puzzle = get_puzzle("one").expect("puzzle one");
+        let source = InputSource::Text(INPUT.to_owned());
+        let run = puzzle.run(&source, &[Part::One]).expect("run");
+        assert_eq!(1, run.parts.len());
+        assert_eq!(Part::One, run.parts[0].part);
+        assert_eq!(Ok(Answer::Unsigned(142)), run.parts[0].answer);
+    }
+
+    #[test]
+    fn run_skips_missing_parts() {
+        let puzzle = get_puzzle("twenty-five").expect("puzzle twenty-five");
+        assert!(!puzzle.has_part(Part::Two));
+        let source = InputSource::Text("a: b\nb: c".to_owned());
+        let run = puzzle.run(&source, &[Part::One, Part::Two]).expect("run");
+        assert_eq!(1, run.parts.len());
+        assert_eq!(Part::One, run.parts[0].part);
     }
 }
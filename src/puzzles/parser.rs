@@ -93,9 +93,37 @@ impl<'a, T> HasNumbers<'a, T> for &'a str {
     }
 }
 
+/// The blank line separated blocks of the input, as slices of it so parse
+/// errors can be located.
+pub(crate) fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut start = None;
+    let mut end = 0;
+    for line in input.split_inclusive('\n') {
+        let offset = line.as_ptr() as usize - input.as_ptr() as usize;
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.len();
+        }
+    }
+    if let Some(start) = start {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
+
 #[cfg(test)]
 mod tests {
-    use super::HasNumbers;
+    use super::{blocks, HasNumbers};
+
+    #[test]
+    fn blocks_test() {
+        assert_eq!(vec!["a\nb\n", "c"], blocks("\na\nb\n\n \nc"));
+    }
 
     #[test]
     fn signed_numbers_test() {
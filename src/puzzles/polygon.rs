@@ -0,0 +1,51 @@
+use num::integer::gcd;
+
+/// Twice the area enclosed by a simple polygon with lattice point vertices,
+/// by the shoelace formula. Doubled so the result is always whole.
+pub(crate) fn double_area(vertices: &[(i64, i64)]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges
+        .map(|(&(x1, y1), &(x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<i64>()
+        .abs()
+}
+
+/// The number of lattice points on the edges of a polygon.
+pub(crate) fn boundary_points(vertices: &[(i64, i64)]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges
+        .map(|(&(x1, y1), &(x2, y2))| gcd((x2 - x1).abs(), (y2 - y1).abs()))
+        .sum()
+}
+
+/// The number of lattice points strictly inside a polygon, by Pick's theorem.
+pub(crate) fn interior_points(vertices: &[(i64, i64)]) -> i64 {
+    (double_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+/// The number of lattice points inside or on the edges of a polygon.
+pub(crate) fn lattice_points(vertices: &[(i64, i64)]) -> i64 {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{boundary_points, double_area, interior_points, lattice_points};
+
+    #[test]
+    fn square() {
+        let square = [(0, 0), (0, 4), (4, 4), (4, 0)];
+        assert_eq!(32, double_area(&square));
+        assert_eq!(16, boundary_points(&square));
+        assert_eq!(9, interior_points(&square));
+        assert_eq!(25, lattice_points(&square));
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = [(0, 0), (3, 0), (0, 3)];
+        assert_eq!(9, double_area(&triangle));
+        assert_eq!(9, boundary_points(&triangle));
+        assert_eq!(1, interior_points(&triangle));
+    }
+}
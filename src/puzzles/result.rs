@@ -2,15 +2,23 @@ use std::{ffi::OsStr, io};
 
 use thiserror::Error;
 
+use crate::Span;
+
 #[derive(Error, Debug)]
 pub enum PuzzleError {
-    #[error("load input file '{path}' failure: {source}")]
+    #[error("load input file '{path}' failure: {source}{}", searched_locations(.searched))]
     LoadInputFailure {
         path: String,
         source: std::io::Error,
+        searched: Vec<String>,
+    },
+    #[error("invalid input at {span}: {reason}\n{snippet}")]
+    InvalidInputAt {
+        span: Span,
+        text: String,
+        reason: String,
+        snippet: String,
     },
-    #[error("invalid input at {line}: {reason}")]
-    InvalidLineInput { line: usize, reason: String },
     #[error("invalid input: {reason}")]
     InvalidInput { reason: String },
     #[error("not implemented")]
@@ -26,14 +34,36 @@ pub enum PuzzleError {
 }
 
 impl PuzzleError {
+    /// A stable snake case name of the error variant for machine readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PuzzleError::LoadInputFailure { .. } => "load_input_failure",
+            PuzzleError::InvalidInputAt { .. } => "invalid_input_at",
+            PuzzleError::InvalidInput { .. } => "invalid_input",
+            PuzzleError::NotImplemented => "not_implemented",
+            PuzzleError::Unexpected { .. } => "unexpected",
+            PuzzleError::UnexpectedErr { .. } => "unexpected_err",
+        }
+    }
+
     pub(crate) fn from_io_error<S: AsRef<OsStr> + ?Sized>(path: &S, source: io::Error) -> Self {
         let path = path.as_ref().to_string_lossy().to_string();
-        PuzzleError::LoadInputFailure { path, source }
+        PuzzleError::LoadInputFailure {
+            path,
+            source,
+            searched: vec![],
+        }
     }
 
-    pub(crate) fn invalid_line_input(line: usize, reason: &str) -> Self {
-        let reason = reason.to_owned();
-        PuzzleError::InvalidLineInput { line, reason }
+    /// An invalid `text`, a slice of the parsed `input`, with its location.
+    pub(crate) fn invalid_input_at(input: &str, text: &str, reason: &str) -> Self {
+        let span = Span::locate(input, text);
+        PuzzleError::InvalidInputAt {
+            span,
+            text: text.to_owned(),
+            reason: reason.to_owned(),
+            snippet: span.snippet(input),
+        }
     }
 
     pub(crate) fn invalid_input(reason: &str) -> Self {
@@ -46,7 +76,6 @@ impl PuzzleError {
         PuzzleError::Unexpected { message }
     }
 
-    #[allow(dead_code)]
     pub(crate) fn unexpected_err(message: &str, err: anyhow::Error) -> Self {
         let message = message.to_owned();
         PuzzleError::UnexpectedErr {
@@ -63,22 +92,32 @@ impl PartialEq for PuzzleError {
                 Self::LoadInputFailure {
                     path: l_path,
                     source: l_source,
+                    searched: l_searched,
                 },
                 Self::LoadInputFailure {
                     path: r_path,
                     source: r_source,
+                    searched: r_searched,
                 },
-            ) => l_path == r_path && l_source.to_string() == r_source.to_string(),
+            ) => {
+                l_path == r_path
+                    && l_source.to_string() == r_source.to_string()
+                    && l_searched == r_searched
+            }
             (
-                Self::InvalidLineInput {
-                    line: l_line,
+                Self::InvalidInputAt {
+                    span: l_span,
+                    text: l_text,
                     reason: l_reason,
+                    ..
                 },
-                Self::InvalidLineInput {
-                    line: r_line,
+                Self::InvalidInputAt {
+                    span: r_span,
+                    text: r_text,
                     reason: r_reason,
+                    ..
                 },
-            ) => l_line == r_line && l_reason == r_reason,
+            ) => l_span == r_span && l_text == r_text && l_reason == r_reason,
             (Self::Unexpected { message: l_message }, Self::Unexpected { message: r_message }) => {
                 l_message == r_message
             }
@@ -97,4 +136,12 @@ impl PartialEq for PuzzleError {
     }
 }
 
+fn searched_locations(searched: &[String]) -> String {
+    if searched.is_empty() {
+        String::new()
+    } else {
+        format!("; searched: {}", searched.join(", "))
+    }
+}
+
 pub type Result<T> = core::result::Result<T, PuzzleError>;
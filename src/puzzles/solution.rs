@@ -0,0 +1,64 @@
+use std::any::Any;
+
+use crate::{Answer, Part, Result};
+
+/// A day's puzzle solution. The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// The parts the day's puzzle has; runs skip any other part.
+    fn parts(&self) -> &'static [Part] {
+        &[Part::One, Part::Two]
+    }
+}
+
+pub(crate) type AnyInput = Box<dyn Any + Send + Sync>;
+
+/// Object safe form of [`Solution`] used to build the puzzle registry.
+pub(crate) trait AnySolution: Sync {
+    fn parse(&self, input: &str) -> Result<AnyInput>;
+
+    fn part_one(&self, input: &AnyInput) -> Result<Answer>;
+
+    fn part_two(&self, input: &AnyInput) -> Result<Answer>;
+
+    fn parts(&self) -> &'static [Part];
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn parse(&self, input: &str) -> Result<AnyInput> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part_one(&self, input: &AnyInput) -> Result<Answer> {
+        Solution::part_one(self, downcast::<S>(input))
+    }
+
+    fn part_two(&self, input: &AnyInput) -> Result<Answer> {
+        Solution::part_two(self, downcast::<S>(input))
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        Solution::parts(self)
+    }
+}
+
+fn downcast<S>(input: &AnyInput) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input must have been parsed by the same solution")
+}
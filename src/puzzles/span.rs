@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+/// A location in a puzzle input, with 1-based line and column numbers counted
+/// in characters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+}
+
+impl Span {
+    /// Finds `text` in `input`. `text` is expected to be a slice of `input`,
+    /// otherwise its first occurrence is used and an unknown text is located
+    /// at the end of the input.
+    pub(crate) fn locate(input: &str, text: &str) -> Span {
+        let offset = slice_offset(input, text)
+            .or_else(|| input.find(text))
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Span {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            len: text.chars().count(),
+        }
+    }
+
+    /// Renders the span's line of `input` with carets under the span.
+    pub(crate) fn snippet(&self, input: &str) -> String {
+        let line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.len.max(1));
+        format!("{gutter} |\n{number} | {line}\n{gutter} | {padding}{carets}")
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+fn slice_offset(input: &str, text: &str) -> Option<usize> {
+    let start = (text.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    (start + text.len() <= input.len() && input.is_char_boundary(start)).then_some(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Span;
+
+    const INPUT: &str = "32T3K 765\nT55J5 68x\nKK677 28";
+
+    #[test]
+    fn locate_slice() {
+        let line = INPUT.lines().nth(1).expect("second line");
+        let span = Span::locate(INPUT, &line[6..]);
+        assert_eq!(
+            Span {
+                line: 2,
+                column: 7,
+                len: 3
+            },
+            span
+        );
+        assert_eq!("  |\n2 | T55J5 68x\n  |       ^^^", span.snippet(INPUT));
+    }
+
+    #[test]
+    fn locate_copied_text() {
+        let span = Span::locate(INPUT, &String::from("28"));
+        assert_eq!(
+            Span {
+                line: 3,
+                column: 7,
+                len: 2
+            },
+            span
+        );
+    }
+}
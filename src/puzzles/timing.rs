@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use crate::{Answer, Part, Result};
+
+/// The durations of a puzzle run's phases and the answer of each part run.
+pub struct PuzzleRun {
+    pub load: Duration,
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub duration: Duration,
+}
+
+/// Summary of repeated measurements of the same phase.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(durations: &[Duration]) -> Option<Stats> {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        Some(Stats {
+            min: *sorted.first()?,
+            median: sorted[sorted.len() / 2],
+            max: *sorted.last()?,
+        })
+    }
+}
+
+pub(crate) fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    #[test]
+    fn stats() {
+        let durations = [3, 1, 5, 2, 4].map(Duration::from_millis);
+        assert_eq!(
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            }),
+            Stats::new(&durations)
+        );
+        assert_eq!(None, Stats::new(&[]));
+    }
+}
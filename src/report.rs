@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use puzzlelib::{Puzzle, PuzzleError, PuzzleRun};
+use serde::Serialize;
+
+use crate::exit::ExitStatus;
+
+pub const FORMAT_NAMES: [&str; 3] = ["text", "json", "csv"];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Format {
+        match name {
+            "json" => Format::Json,
+            "csv" => Format::Csv,
+            _ => Format::Text,
+        }
+    }
+}
+
+/// One puzzle part's outcome. Load, parse and not implemented failures have no
+/// part since they apply to the whole day.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: i32,
+    pub name: String,
+    pub part: Option<&'static str>,
+    pub answer: Option<String>,
+    pub duration_ns: Option<u64>,
+    pub error_kind: Option<&'static str>,
+    pub error: Option<String>,
+    #[serde(skip)]
+    pub exit_status: Option<ExitStatus>,
+}
+
+impl Record {
+    pub fn from_run(
+        puzzle: &Puzzle,
+        result: std::result::Result<PuzzleRun, PuzzleError>,
+    ) -> Vec<Record> {
+        let record = |part, answer, duration: Option<Duration>, err: Option<PuzzleError>| Record {
+            day: puzzle.day(),
+            name: puzzle.name().to_owned(),
+            part,
+            answer,
+            duration_ns: duration.map(|duration| duration.as_nanos() as u64),
+            error_kind: err.as_ref().map(PuzzleError::kind),
+            exit_status: err.as_ref().map(ExitStatus::from_error),
+            error: err.map(|err| err.to_string()),
+        };
+        match result {
+            Ok(run) => run
+                .parts
+                .into_iter()
+                .map(|part_run| match part_run.answer {
+                    Ok(answer) => record(
+                        Some(part_run.part.name()),
+                        Some(answer.to_string()),
+                        Some(part_run.duration),
+                        None,
+                    ),
+                    Err(err) => record(
+                        Some(part_run.part.name()),
+                        None,
+                        Some(part_run.duration),
+                        Some(err),
+                    ),
+                })
+                .collect(),
+            Err(err) => vec![record(None, None, None, Some(err))],
+        }
+    }
+
+    pub fn is_not_implemented(&self) -> bool {
+        self.exit_status == Some(ExitStatus::NotImplemented)
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.exit_status.is_some() && !self.is_not_implemented()
+    }
+
+    fn status(&self) -> &'static str {
+        if self.is_not_implemented() {
+            "not implemented"
+        } else if self.is_failure() {
+            "ERROR"
+        } else {
+            "ok"
+        }
+    }
+}
+
+pub fn print_records(records: &[Record], format: Format) -> Result<()> {
+    match format {
+        Format::Text => print_table(records),
+        Format::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        Format::Csv => print_csv(records),
+    }
+    Ok(())
+}
+
+fn print_table(records: &[Record]) {
+    println!(
+        "{:<13} {:<4} {:<15} {:>14} Answer",
+        "Day", "Part", "Status", "Time"
+    );
+    for record in records {
+        let duration = record
+            .duration_ns
+            .map(|nanos| format!("{:?}", Duration::from_nanos(nanos)))
+            .unwrap_or_default();
+        let answer = match (&record.answer, &record.error) {
+            (Some(answer), _) => answer.as_str(),
+            // located input errors continue with a snippet that doesn't fit a table row
+            (None, Some(error)) if record.is_failure() => error.lines().next().unwrap_or_default(),
+            _ => "",
+        };
+        println!(
+            "{:<13} {:<4} {:<15} {:>14} {}",
+            record.name,
+            record.part.unwrap_or("-"),
+            record.status(),
+            duration,
+            answer
+        );
+    }
+
+    println!("{}", summary(records));
+}
+
+/// Counts days rather than records, since a load or parse failure is a
+/// single record for both parts.
+fn summary(records: &[Record]) -> String {
+    let (mut answered, mut failed, mut not_implemented) = (0, 0, 0);
+    for day in records.chunk_by(|a, b| a.day == b.day) {
+        if day.iter().any(Record::is_not_implemented) {
+            not_implemented += 1;
+        } else if day.iter().any(Record::is_failure) {
+            failed += 1;
+        } else {
+            answered += 1;
+        }
+    }
+    format!("days: {answered} answered, {failed} failed, {not_implemented} not implemented")
+}
+
+fn print_csv(records: &[Record]) {
+    println!("day,name,part,answer,duration_ns,error_kind,error");
+    for record in records {
+        let fields = [
+            record.day.to_string(),
+            record.name.clone(),
+            record.part.unwrap_or_default().to_owned(),
+            record.answer.clone().unwrap_or_default(),
+            record
+                .duration_ns
+                .map(|nanos| nanos.to_string())
+                .unwrap_or_default(),
+            record.error_kind.unwrap_or_default().to_owned(),
+            record.error.clone().unwrap_or_default(),
+        ];
+        let fields = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>();
+        println!("{}", fields.join(","));
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, time::Duration};
+
+    use puzzlelib::{get_puzzle, Answer, Part, PartRun, PuzzleError, PuzzleRun};
+    use serde_json::json;
+
+    use super::{csv_field, summary, Record};
+
+    fn run(answers: Vec<Result<Answer, PuzzleError>>) -> PuzzleRun {
+        PuzzleRun {
+            load: Duration::from_micros(10),
+            parse: Duration::from_micros(20),
+            parts: [Part::One, Part::Two]
+                .into_iter()
+                .zip(answers)
+                .map(|(part, answer)| PartRun {
+                    part,
+                    answer,
+                    duration: Duration::from_micros(30),
+                })
+                .collect(),
+        }
+    }
+
+    fn day_records(day: &str, result: Result<PuzzleRun, PuzzleError>) -> Vec<Record> {
+        let puzzle = get_puzzle(day).expect("puzzle");
+        Record::from_run(&puzzle, result)
+    }
+
+    #[test]
+    fn summary_counts_days() {
+        let records = [
+            day_records("one", Ok(run(vec![Ok(1u64.into()), Ok(2u64.into())]))),
+            day_records(
+                "two",
+                Ok(run(vec![
+                    Ok(3u64.into()),
+                    Err(PuzzleError::Unexpected {
+                        message: "bad".to_owned(),
+                    }),
+                ])),
+            ),
+            day_records(
+                "three",
+                Err(PuzzleError::InvalidInput {
+                    reason: "bad".to_owned(),
+                }),
+            ),
+            day_records("four", Err(PuzzleError::NotImplemented)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        assert_eq!(
+            "days: 1 answered, 2 failed, 1 not implemented",
+            summary(&records)
+        );
+    }
+
+    #[test]
+    fn csv_field_quoting() {
+        assert_eq!("42", csv_field("42"));
+        assert_eq!("\"1,2\"", csv_field("1,2"));
+        assert_eq!("\"say \"\"hi\"\"\"", csv_field("say \"hi\""));
+        assert_eq!("\"a\nb\"", csv_field("a\nb"));
+    }
+
+    #[test]
+    fn json_records() {
+        let records = day_records("one", Ok(run(vec![Ok(142u64.into())])));
+        assert_eq!(
+            json!([{
+                "day": 1,
+                "name": "one",
+                "part": "one",
+                "answer": "142",
+                "duration_ns": 30000,
+                "error_kind": null,
+                "error": null,
+            }]),
+            serde_json::to_value(&records).expect("serialize")
+        );
+
+        let records = day_records(
+            "two",
+            Err(PuzzleError::LoadInputFailure {
+                path: "day_02.input".to_owned(),
+                source: io::Error::new(io::ErrorKind::NotFound, "input file not found"),
+                searched: vec![],
+            }),
+        );
+        assert_eq!(
+            json!([{
+                "day": 2,
+                "name": "two",
+                "part": null,
+                "answer": null,
+                "duration_ns": null,
+                "error_kind": "load_input_failure",
+                "error": "load input file 'day_02.input' failure: input file not found",
+            }]),
+            serde_json::to_value(&records).expect("serialize")
+        );
+    }
+}